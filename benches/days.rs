@@ -0,0 +1,62 @@
+use std::fmt;
+use divan::{AllocProfiler, Bencher};
+
+use aoc25::{read_input, Day, InputSource, Stage, YEARS};
+
+#[global_allocator]
+static ALLOC: AllocProfiler = AllocProfiler::system();
+
+fn main() {
+	divan::main();
+}
+
+#[derive(Copy, Clone)]
+struct Step {
+	day: &'static dyn Day,
+	stage: Stage,
+}
+
+impl fmt::Display for Step {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}/day{:0>2}/{}", self.day.year(), self.day.day(), self.stage)
+	}
+}
+
+// parsing and each part of every registered day, across every year, that has an input to run against
+fn steps() -> Vec<Step> {
+	YEARS.iter()
+		.flat_map(|year| year.days)
+		.filter(|day| match read_input(day.year(), day.day(), &InputSource::Default) {
+			Ok(_) => true,
+			Err(err) => {
+				eprintln!("skipping day {:0>2} of {}: {}", day.day(), day.year(), err);
+				false
+			},
+		})
+		.flat_map(|&day| [Stage::Parse, Stage::Part1, Stage::Part2].map(|stage| Step { day, stage }))
+		.collect()
+}
+
+#[divan::bench(args = steps(), min_time = 5)]
+fn solve(bencher: Bencher, step: Step) {
+	let input = read_input(step.day.year(), step.day.day(), &InputSource::Default).unwrap();
+
+	// the parts are timed against an already parsed input, so parsing is only counted once
+	match step.stage {
+		Stage::Parse => bencher.bench_local(|| {
+			let _ = step.day.parse(input.as_str());
+		}),
+		Stage::Part1 | Stage::Part2 => {
+			bencher.with_inputs(|| {
+				step.day.parse(input.as_str()).unwrap()
+			}).bench_local_refs(|parsed| {
+				let _ = match step.stage {
+					Stage::Part1 => step.day.solve_part1(parsed),
+					_ => step.day.solve_part2(parsed),
+				};
+			})
+		},
+		// work shared between the parts can only be timed inside solve_both, so steps never asks for it
+		Stage::Shared => unreachable!("shared work isn't benched on its own"),
+	}
+}
\ No newline at end of file
@@ -0,0 +1,134 @@
+use std::fmt;
+use num::BigInt;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+	Unsigned(u64),
+	Signed(i64),
+	Big(BigInt),
+	Text(String),
+}
+
+impl Answer {
+	// reads an answer back from text, preferring the narrowest numeric representation
+	pub fn parse(answer: &str) -> Self {
+		let answer = answer.trim();
+		if let Ok(num) = answer.parse::<u64>() {
+			Answer::Unsigned(num)
+		} else if let Ok(num) = answer.parse::<i64>() {
+			Answer::Signed(num)
+		} else if let Ok(num) = answer.parse::<BigInt>() {
+			Answer::Big(num)
+		} else {
+			Answer::Text(String::from(answer))
+		}
+	}
+}
+
+impl fmt::Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Answer::Unsigned(num) => num.fmt(f),
+			Answer::Signed(num) => num.fmt(f),
+			Answer::Big(num) => num.fmt(f),
+			Answer::Text(text) => text.fmt(f),
+		}
+	}
+}
+
+impl PartialEq for Answer {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+			(Answer::Signed(a), Answer::Signed(b)) => a == b,
+			// answers are submitted as text, so two answers that print the same are the same answer
+			_ => self.to_string() == other.to_string(),
+		}
+	}
+}
+impl Eq for Answer {}
+
+impl PartialEq<u64> for Answer {
+	fn eq(&self, other: &u64) -> bool {
+		*self == Answer::Unsigned(*other)
+	}
+}
+impl PartialEq<Answer> for u64 {
+	fn eq(&self, other: &Answer) -> bool {
+		*other == *self
+	}
+}
+impl PartialEq<i64> for Answer {
+	fn eq(&self, other: &i64) -> bool {
+		*self == Answer::Signed(*other)
+	}
+}
+impl PartialEq<&str> for Answer {
+	fn eq(&self, other: &&str) -> bool {
+		*self == Answer::parse(other)
+	}
+}
+impl PartialEq<Answer> for &str {
+	fn eq(&self, other: &Answer) -> bool {
+		*other == *self
+	}
+}
+
+impl From<u64> for Answer {
+	fn from(num: u64) -> Self {
+		Answer::Unsigned(num)
+	}
+}
+impl From<u32> for Answer {
+	fn from(num: u32) -> Self {
+		Answer::Unsigned(num as u64)
+	}
+}
+impl From<usize> for Answer {
+	fn from(num: usize) -> Self {
+		Answer::Unsigned(num as u64)
+	}
+}
+impl From<i64> for Answer {
+	fn from(num: i64) -> Self {
+		Answer::Signed(num)
+	}
+}
+impl From<BigInt> for Answer {
+	fn from(num: BigInt) -> Self {
+		Answer::Big(num)
+	}
+}
+impl From<String> for Answer {
+	fn from(text: String) -> Self {
+		Answer::Text(text)
+	}
+}
+impl From<&str> for Answer {
+	fn from(text: &str) -> Self {
+		Answer::Text(String::from(text))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use num::BigInt;
+	use crate::answer::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!(Answer::Unsigned(3121910778619), Answer::parse("3121910778619"));
+		assert!(matches!(Answer::parse("-42"), Answer::Signed(-42)));
+		assert!(matches!(Answer::parse("123456789012345678901234567890"), Answer::Big(_)));
+		assert!(matches!(Answer::parse("EFHJKLMN"), Answer::Text(_)));
+	}
+
+	#[test]
+	fn test_equality() {
+		assert_eq!(40, Answer::from(40usize));
+		assert_eq!(Answer::from(BigInt::from(40)), Answer::from(40u64));
+		assert_eq!(Answer::from(-7i64), Answer::Big(BigInt::from(-7)));
+		assert_eq!("EFHJKLMN", Answer::from("EFHJKLMN"));
+		assert_ne!(Answer::from(7u64), Answer::from(-7i64));
+	}
+}
\ No newline at end of file
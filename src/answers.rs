@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending, not_line_ending, one_of, space0, space1, u8};
+use nom::combinator::{map, value};
+use nom::error::context;
+use nom::multi::separated_list0;
+use nom::sequence::{preceded, terminated};
+use nom::Parser;
+use crate::parse::{parse_complete, IResult, ParseDiagnostic};
+use crate::{Answer, Error, Normalization, Result};
+
+// expected answers for real inputs, one "<day> <part> <answer>" line per part
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AnswerStore {
+	answers: BTreeMap<(u8, u8), Answer>,
+}
+
+type Entry = ((u8, u8), Answer);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+	Correct,
+	Incorrect { expected: Answer },
+	Unknown,
+}
+
+fn parse_entry(input: &str) -> IResult<&str, Entry> {
+	let (input, day) = u8(input)?;
+	let (input, _) = space1(input)?;
+	let (input, part) = context("part 1 or 2", one_of("12")).parse(input)?;
+	let (input, _) = space1(input)?;
+	let (input, answer) = context("an answer", not_line_ending).parse(input)?;
+	let part = if part == '1' { 1 } else { 2 };
+	Ok((input, ((day, part), Answer::parse(answer))))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Option<Entry>> {
+	alt((
+		value(None, preceded(char('#'), not_line_ending)),
+		map(parse_entry, Some),
+		value(None, space0),
+	)).parse(input)
+}
+
+impl AnswerStore {
+	pub fn parse(input: &str) -> Result<Self> {
+		let input = Normalization::DEFAULT.apply(input);
+		let entries = parse_complete(input.as_str(), terminated(
+			separated_list0(line_ending, parse_line),
+			space0,
+		))?;
+
+		let answers = entries.into_iter().flatten().collect();
+		Ok(Self { answers })
+	}
+
+	// an example's answers are all for the day it's filed under, so an entry for any other day is a mistake
+	pub fn parse_for_day(input: &str, day: u8) -> Result<Self> {
+		let store = Self::parse(input)?;
+		let input = Normalization::DEFAULT.apply(input);
+		let other_day = input.lines().position(|line| matches!(parse_entry(line), Ok((_, ((entry_day, _), _))) if entry_day != day));
+		match other_day {
+			Some(line_ix) => Err(Error::Parse(ParseDiagnostic::at(&input, line_ix, 0, format!("answers for day {:0>2}", day)))),
+			None => Ok(store),
+		}
+	}
+
+	// a missing file is an empty store, so verifying works before anything has been recorded
+	pub fn load(path: &Path) -> Result<Self> {
+		Self::load_with(path, Self::parse)
+	}
+
+	pub fn load_for_day(path: &Path, day: u8) -> Result<Self> {
+		Self::load_with(path, |input| Self::parse_for_day(input, day))
+	}
+
+	// parse errors name the file, since there may be one of these per example
+	fn load_with(path: &Path, parse: impl FnOnce(&str) -> Result<Self>) -> Result<Self> {
+		let input = match fs::read_to_string(path) {
+			Ok(input) => input,
+			Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+			Err(source) => return Err(Error::Io { path: Some(path.to_path_buf()), source }),
+		};
+		parse(&input).map_err(|err| match err {
+			Error::Parse(ParseDiagnostic { location, expected }) => Error::Parse(ParseDiagnostic {
+				location,
+				expected: expected.into_iter().map(|expected| format!("{} in {}", expected, path.display())).collect(),
+			}),
+			err => err,
+		})
+	}
+
+	pub fn save(&self, path: &Path) -> Result<()> {
+		fs::write(path, self.to_string()).map_err(|source| Error::Io { path: Some(path.to_path_buf()), source })
+	}
+
+	pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+		self.answers.get(&(day, part))
+	}
+
+	pub fn insert(&mut self, day: u8, part: u8, answer: Answer) -> Option<Answer> {
+		self.answers.insert((day, part), answer)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.answers.is_empty()
+	}
+
+	pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+		match self.get(day, part) {
+			Some(expected) if expected == answer => Verdict::Correct,
+			Some(expected) => Verdict::Incorrect { expected: expected.clone() },
+			None => Verdict::Unknown,
+		}
+	}
+}
+
+impl fmt::Display for AnswerStore {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "# day part answer")?;
+		self.answers.iter().try_for_each(|((day, part), answer)| writeln!(f, "{:0>2} {} {}", day, part, answer))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::answers::*;
+
+	const TEST: &str = "# day part answer\r
+01 1 3
+01 2 6
+
+# day 10 is still unsolved
+11 1 5
+";
+
+	#[test]
+	fn test_parse() -> Result<()> {
+		let store = AnswerStore::parse(TEST)?;
+		assert_eq!(Some(&Answer::from(3u64)), store.get(1, 1));
+		assert_eq!(Some(&Answer::from(5u64)), store.get(11, 1));
+		assert_eq!(None, store.get(11, 2));
+
+		assert_eq!(Verdict::Correct, store.check(1, 2, &6u64.into()));
+		assert_eq!(Verdict::Incorrect { expected: 3u64.into() }, store.check(1, 1, &4u64.into()));
+		assert_eq!(Verdict::Unknown, store.check(10, 1, &7u64.into()));
+
+		assert_eq!(store, AnswerStore::parse(&store.to_string())?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_error() {
+		let Err(Error::Parse(diagnostic)) = AnswerStore::parse("01 1 3\n01 3 6") else { panic!("expected a parse error") };
+		assert_eq!(2, diagnostic.location.unwrap().line);
+	}
+
+	#[test]
+	fn test_parse_for_day() -> Result<()> {
+		assert_eq!(AnswerStore::parse("# day 11\n11 1 5")?, AnswerStore::parse_for_day("# day 11\n11 1 5", 11)?);
+		let Err(Error::Parse(diagnostic)) = AnswerStore::parse_for_day("# day 11\n11 1 5\n10 2 2", 11) else { panic!("expected a parse error") };
+		assert_eq!("expected answers for day 11 at line 3, column 1\n  |\n3 | 10 2 2\n  | ^", diagnostic.to_string());
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, line_ending, not_line_ending, space0, space1, u64, u8};
+use nom::combinator::{map, value};
+use nom::error::context;
+use nom::multi::separated_list0;
+use nom::sequence::{preceded, terminated};
+use nom::Parser;
+use crate::parse::{parse_complete, IResult};
+use crate::{Day, Error, Normalization, Result, Stage};
+
+// median solve times per part, and of any work the parts share, one "<day> <shared|part1|part2> <nanoseconds>" line each
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Baseline {
+	timings: BTreeMap<(u8, Stage), Duration>,
+}
+
+type Entry = ((u8, Stage), Duration);
+
+// slowdowns smaller than this are timer noise, however large they are relative to the baseline
+const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Comparison {
+	pub day: u8,
+	pub stage: Stage,
+	pub baseline: Duration,
+	pub current: Duration,
+}
+impl Comparison {
+	// percentage change from the baseline, positive when slower
+	pub fn change(&self) -> f64 {
+		let baseline = self.baseline.as_secs_f64();
+		if baseline == 0.0 {
+			return 0.0;
+		}
+		(self.current.as_secs_f64() - baseline) / baseline * 100.0
+	}
+
+	pub fn is_regression(&self, threshold: f64) -> bool {
+		self.change() > threshold && self.current.saturating_sub(self.baseline) > NOISE_FLOOR
+	}
+}
+
+fn median(mut timings: Vec<Duration>) -> Option<Duration> {
+	timings.sort_unstable();
+	let mid = timings.len() / 2;
+	match timings.len() {
+		0 => None,
+		len if len % 2 == 0 => Some((timings[mid - 1] + timings[mid]) / 2),
+		_ => Some(timings[mid]),
+	}
+}
+
+fn parse_entry(input: &str) -> IResult<&str, Entry> {
+	let (input, day) = u8(input)?;
+	let (input, _) = space1(input)?;
+	let (input, stage) = context("shared, part1 or part2", alt((
+		value(Stage::Shared, tag("shared")),
+		value(Stage::Part1, tag("part1")),
+		value(Stage::Part2, tag("part2")),
+	))).parse(input)?;
+	let (input, _) = space1(input)?;
+	let (input, nanos) = u64(input)?;
+	Ok((input, ((day, stage), Duration::from_nanos(nanos))))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Option<Entry>> {
+	alt((
+		value(None, preceded(char('#'), not_line_ending)),
+		map(parse_entry, Some),
+		value(None, space0),
+	)).parse(input)
+}
+
+impl Baseline {
+	pub fn parse(input: &str) -> Result<Self> {
+		let input = Normalization::DEFAULT.apply(input);
+		let entries = parse_complete(input.as_str(), terminated(
+			separated_list0(line_ending, parse_line),
+			space0,
+		))?;
+
+		let timings = entries.into_iter().flatten().collect();
+		Ok(Self { timings })
+	}
+
+	// a missing file is an empty baseline, so the first run just has nothing to compare against
+	pub fn load(path: &Path) -> Result<Self> {
+		match fs::read_to_string(path) {
+			Ok(input) => Self::parse(&input),
+			Err(source) if source.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+			Err(source) => Err(Error::Io { path: Some(path.to_path_buf()), source }),
+		}
+	}
+
+	pub fn save(&self, path: &Path) -> Result<()> {
+		fs::write(path, self.to_string()).map_err(|source| Error::Io { path: Some(path.to_path_buf()), source })
+	}
+
+	pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+		self.timings.get(&(day, stage)).copied()
+	}
+
+	pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) -> Option<Duration> {
+		self.timings.insert((day, stage), median)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.timings.is_empty()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (u8, Stage, Duration)> + '_ {
+		self.timings.iter().map(|(&(day, stage), &median)| (day, stage, median))
+	}
+
+	// takes every timing from other, keeping ours for parts it didn't measure
+	pub fn merge(&mut self, other: &Baseline) {
+		other.iter().for_each(|(day, stage, median)| {
+			self.insert(day, stage, median);
+		});
+	}
+
+	// solves both parts runs times and keeps the median of each part that succeeded, and of any work they share
+	// a part that fails on any run isn't timed at all, and its first error is handed back instead
+	pub fn measure(&mut self, solution: &dyn Day, input: &str, runs: usize) -> Result<Vec<(Stage, Error)>> {
+		let mut shared = vec![];
+		let mut parts = [(Stage::Part1, vec![], None), (Stage::Part2, vec![], None)];
+		for _ in 0..runs {
+			let run = solution.run(input)?;
+			if !run.shared.is_zero() {
+				shared.push(run.shared);
+			}
+			for ((_, timings, failure), part) in parts.iter_mut().zip([run.part1, run.part2]) {
+				match part.answer {
+					Ok(_) => timings.push(part.elapsed),
+					Err(err) => {
+						failure.get_or_insert(err);
+					},
+				}
+			}
+		}
+
+		if let Some(median) = median(shared) {
+			self.insert(solution.day(), Stage::Shared, median);
+		}
+		let mut failures = vec![];
+		for (stage, timings, failure) in parts {
+			match failure {
+				Some(err) => failures.push((stage, err)),
+				None => if let Some(median) = median(timings) {
+					self.insert(solution.day(), stage, median);
+				},
+			}
+		}
+		Ok(failures)
+	}
+
+	// every part timed in both baselines, in day order
+	pub fn compare(&self, current: &Baseline) -> Vec<Comparison> {
+		self.timings.iter()
+			.filter_map(|(&(day, stage), &baseline)| {
+				let current = current.get(day, stage)?;
+				Some(Comparison { day, stage, baseline, current })
+			})
+			.collect()
+	}
+}
+
+impl fmt::Display for Baseline {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "# day part median-nanoseconds")?;
+		self.timings.iter().try_for_each(|((day, stage), median)| writeln!(f, "{:0>2} {} {}", day, stage, median.as_nanos()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::baseline::*;
+	use crate::get_day;
+
+	#[test]
+	fn test_median() {
+		let millis = |millis: &[u64]| millis.iter().copied().map(Duration::from_millis).collect::<Vec<_>>();
+		assert_eq!(None, median(vec![]));
+		assert_eq!(Some(Duration::from_millis(3)), median(millis(&[9, 1, 3])));
+		assert_eq!(Some(Duration::from_millis(4)), median(millis(&[9, 1, 3, 5])));
+	}
+
+	#[test]
+	fn test_compare() -> Result<()> {
+		let baseline = Baseline::parse("# day part median-nanoseconds\n01 part1 1000\n01 part2 2000\n02 part1 500\n")?;
+		let current = Baseline::parse("01 part1 1200\n01 part2 1000\n02 part1 900\n")?;
+		assert_eq!(baseline, Baseline::parse(&baseline.to_string())?);
+
+		let comparisons = baseline.compare(&current);
+		assert_eq!(3, comparisons.len());
+		assert!((comparisons[0].change() - 20.0).abs() < 1e-9);
+		assert!((comparisons[1].change() + 50.0).abs() < 1e-9);
+		// 80% slower, but only by 400ns
+		assert!(!comparisons[2].is_regression(10.0));
+
+		let slower = Comparison { day: 1, stage: Stage::Part1, baseline: Duration::from_millis(10), current: Duration::from_millis(12) };
+		assert!(slower.is_regression(10.0));
+		assert!(!slower.is_regression(25.0));
+		Ok(())
+	}
+
+	#[test]
+	fn test_measure() -> Result<()> {
+		let mut baseline = Baseline::default();
+		let failures = baseline.measure(get_day(2025, 11)?, "svr: fft\nfft: dac\ndac: out", 3)?;
+		// part 1 has no "you" device, so only part 2 gets a timing and part 1 is reported instead
+		assert!(matches!(failures.as_slice(), [(Stage::Part1, Error::NoSolution(_))]));
+		assert_eq!(None, baseline.get(11, Stage::Part1));
+		assert!(baseline.get(11, Stage::Part2).is_some());
+		Ok(())
+	}
+}
\ No newline at end of file
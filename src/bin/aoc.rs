@@ -0,0 +1,865 @@
+use std::env;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+use aoc25::{answers_file, baseline_file, count_allocations, default_jobs, default_scale, discover_examples, examples_dir, generate_input, get_day, get_year, input_candidates, latest_year, parallel_map, trace, with_cancellation, read_input, records, scaffold_day, start_day, to_csv, to_json, AllocStats, AnswerStore, Baseline, CancelToken, Comparison, CountingAllocator, Day, DayRun, Error, Event, InputSource, PartRun, Record, Result, Stage, Verbosity, Verdict, Watcher};
+
+const USAGE: &str = "usage: aoc [--year <yyyy>] <day|all> [--part 1|2] [--input <path>|-] [--input-dir <dir>] [--format text|json|csv] [--timeout <secs>] [--jobs <n>]\n       aoc <day> --explain[=summary|detail] [--part 1|2] [--input <path>|-] [--input-dir <dir>] [--timeout <secs>]\n       aoc watch <day> [--part 1|2] [--input <path>] [--input-dir <dir>] [--interval <ms>] [--timeout <secs>]\n       aoc verify [--record] [--input-dir <dir>] [--timeout <secs>]\n       aoc bench [--runs <n>] [--threshold <percent>] [--record] [--input-dir <dir>]\n       aoc new <day> [--title <title>]\n       aoc generate <day> [--seed <n>] [--scale <n>]\n       aoc render <day> [--input <path>|-] [--input-dir <dir>] [--output <path.txt|.ppm|.svg>]\n\nevery command works on the latest registered year unless given --year\n--input-dir holds that year's inputs, AOC_INPUT_DIR holds a directory of inputs per year";
+
+const DEFAULT_RUNS: usize = 11;
+const DEFAULT_THRESHOLD: u32 = 10;
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Format {
+	Text,
+	Json,
+	Csv,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Target {
+	Day(u8),
+	All,
+	Verify,
+	Bench,
+	New(u8),
+	Generate(u8),
+	Render(u8),
+	Watch(u8),
+	// asked for explicitly, so printing the usage is all there is to do
+	Help,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Args {
+	target: Target,
+	year: u16,
+	part: Option<u8>,
+	input: InputSource,
+	record: bool,
+	title: Option<String>,
+	format: Format,
+	runs: usize,
+	threshold: u32,
+	seed: u64,
+	scale: Option<usize>,
+	output: Option<PathBuf>,
+	explain: Option<Verbosity>,
+	timeout: Option<Duration>,
+	jobs: Option<usize>,
+	interval: Duration,
+}
+
+impl Default for Args {
+	fn default() -> Self {
+		Self {
+			target: Target::All,
+			year: latest_year(),
+			part: None,
+			input: InputSource::Default,
+			record: false,
+			title: None,
+			format: Format::Text,
+			runs: DEFAULT_RUNS,
+			threshold: DEFAULT_THRESHOLD,
+			seed: 0,
+			scale: None,
+			output: None,
+			explain: None,
+			timeout: None,
+			jobs: None,
+			interval: DEFAULT_INTERVAL,
+		}
+	}
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args> {
+	let mut target = None;
+	let mut year = None;
+	let mut part = None;
+	let mut input = InputSource::Default;
+	let mut record = false;
+	let mut title = None;
+	let mut format = Format::Text;
+	let mut runs = None;
+	let mut threshold = None;
+	let mut seed = None;
+	let mut scale = None;
+	let mut output = None;
+	let mut explain = None;
+	let mut timeout = None;
+	let mut jobs = None;
+	let mut interval = None;
+
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"-p" | "--part" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				part = match value.as_str() {
+					"1" => Some(1),
+					"2" => Some(2),
+					_ => return Err(Error::usage(format!("part must be 1 or 2, got '{}'", value))),
+				};
+			},
+			"-i" | "--input" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				input = if value == "-" { InputSource::Stdin } else { InputSource::File(PathBuf::from(value)) };
+			},
+			"-d" | "--input-dir" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				input = InputSource::Dir(PathBuf::from(value));
+			},
+			"-f" | "--format" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				format = match value.as_str() {
+					"text" => Format::Text,
+					"json" => Format::Json,
+					"csv" => Format::Csv,
+					_ => return Err(Error::usage(format!("format must be text, json or csv, got '{}'", value))),
+				};
+			},
+			"-n" | "--runs" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				runs = match value.parse::<usize>() {
+					Ok(runs) if runs > 0 => Some(runs),
+					_ => return Err(Error::usage(format!("runs must be a positive number, got '{}'", value))),
+				};
+			},
+			"--threshold" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				let percent = value.strip_suffix('%').unwrap_or(&value);
+				threshold = Some(percent.parse::<u32>().map_err(|_| Error::usage(format!("threshold must be a whole percentage, got '{}'", value)))?);
+			},
+			"-s" | "--seed" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				seed = Some(value.parse::<u64>().map_err(|_| Error::usage(format!("seed must be a number, got '{}'", value)))?);
+			},
+			"--scale" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				scale = match value.parse::<usize>() {
+					Ok(scale) if scale > 0 => Some(scale),
+					_ => return Err(Error::usage(format!("scale must be a positive number, got '{}'", value))),
+				};
+			},
+			"-o" | "--output" => {
+				output = Some(PathBuf::from(args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?));
+			},
+			"-e" | "--explain" | "--explain=summary" => explain = Some(Verbosity::Summary),
+			"--explain=detail" => explain = Some(Verbosity::Detail),
+			_ if arg.starts_with("--explain=") => return Err(Error::usage(format!("explain must be summary or detail, got '{}'", &arg["--explain=".len()..]))),
+			"-T" | "--timeout" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				timeout = match value.parse::<f64>().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok()) {
+					Some(timeout) if !timeout.is_zero() => Some(timeout),
+					_ => return Err(Error::usage(format!("timeout must be a positive number of seconds, got '{}'", value))),
+				};
+			},
+			"-j" | "--jobs" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				jobs = match value.parse::<usize>() {
+					Ok(jobs) if jobs > 0 => Some(jobs),
+					_ => return Err(Error::usage(format!("jobs must be a positive number, got '{}'", value))),
+				};
+			},
+			"--interval" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				interval = match value.parse::<u64>() {
+					Ok(millis) if millis > 0 => Some(Duration::from_millis(millis)),
+					_ => return Err(Error::usage(format!("interval must be a positive number of milliseconds, got '{}'", value))),
+				};
+			},
+			"-y" | "--year" => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?;
+				year = Some(value.parse::<u16>().map_err(|_| Error::usage(format!("year must be a number like 2025, got '{}'", value)))?);
+			},
+			"-r" | "--record" => record = true,
+			"-t" | "--title" => {
+				title = Some(args.next().ok_or_else(|| Error::usage(format!("missing value for {}", arg)))?);
+			},
+			"-h" | "--help" => return Ok(Args { target: Target::Help, ..Args::default() }),
+			"all" if target.is_none() => target = Some(Target::All),
+			"verify" if target.is_none() => target = Some(Target::Verify),
+			"bench" if target.is_none() => target = Some(Target::Bench),
+			"new" if target.is_none() => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing day for new\n{}", USAGE)))?;
+				let day = value.parse::<u8>().map_err(|_| Error::usage(format!("invalid day '{}'\n{}", value, USAGE)))?;
+				target = Some(Target::New(day));
+			},
+			"generate" if target.is_none() => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing day for generate\n{}", USAGE)))?;
+				let day = value.parse::<u8>().map_err(|_| Error::usage(format!("invalid day '{}'\n{}", value, USAGE)))?;
+				target = Some(Target::Generate(day));
+			},
+			"watch" if target.is_none() => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing day for watch\n{}", USAGE)))?;
+				let day = value.parse::<u8>().map_err(|_| Error::usage(format!("invalid day '{}'\n{}", value, USAGE)))?;
+				target = Some(Target::Watch(day));
+			},
+			"render" if target.is_none() => {
+				let value = args.next().ok_or_else(|| Error::usage(format!("missing day for render\n{}", USAGE)))?;
+				let day = value.parse::<u8>().map_err(|_| Error::usage(format!("invalid day '{}'\n{}", value, USAGE)))?;
+				target = Some(Target::Render(day));
+			},
+			_ if target.is_none() && !arg.starts_with('-') => {
+				let day = arg.parse::<u8>().map_err(|_| Error::usage(format!("invalid day '{}'\n{}", arg, USAGE)))?;
+				target = Some(Target::Day(day));
+			},
+			_ => return Err(Error::usage(format!("unexpected argument '{}'\n{}", arg, USAGE))),
+		}
+	}
+
+	let target = target.ok_or_else(|| Error::usage(USAGE))?;
+	if target == Target::All && (part.is_some() || matches!(input, InputSource::File(_) | InputSource::Stdin)) {
+		return Err(Error::usage(format!("'all' always runs both parts against each day's own input\n{}", USAGE)));
+	}
+	if matches!(target, Target::Verify | Target::Bench) && (part.is_some() || matches!(input, InputSource::File(_) | InputSource::Stdin)) {
+		return Err(Error::usage(format!("'verify' and 'bench' always run both parts against each day's own input\n{}", USAGE)));
+	}
+	if record && !matches!(target, Target::Verify | Target::Bench) {
+		return Err(Error::usage(format!("--record only applies to 'verify' and 'bench'\n{}", USAGE)));
+	}
+	if (runs.is_some() || threshold.is_some()) && target != Target::Bench {
+		return Err(Error::usage(format!("--runs and --threshold only apply to 'bench'\n{}", USAGE)));
+	}
+	if title.is_some() && !matches!(target, Target::New(_)) {
+		return Err(Error::usage(format!("--title only applies to 'new'\n{}", USAGE)));
+	}
+	if matches!(target, Target::New(_)) && (part.is_some() || input != InputSource::Default) {
+		return Err(Error::usage(format!("'new' only takes a --title\n{}", USAGE)));
+	}
+	if matches!(target, Target::Generate(_)) && (part.is_some() || input != InputSource::Default) {
+		return Err(Error::usage(format!("'generate' only takes a --seed and --scale\n{}", USAGE)));
+	}
+	if (seed.is_some() || scale.is_some()) && !matches!(target, Target::Generate(_)) {
+		return Err(Error::usage(format!("--seed and --scale only apply to 'generate'\n{}", USAGE)));
+	}
+	if matches!(target, Target::Render(_)) && part.is_some() {
+		return Err(Error::usage(format!("'render' draws the whole input, not a part\n{}", USAGE)));
+	}
+	if output.is_some() && !matches!(target, Target::Render(_)) {
+		return Err(Error::usage(format!("--output only applies to 'render'\n{}", USAGE)));
+	}
+	if explain.is_some() && (!matches!(target, Target::Day(_)) || format != Format::Text) {
+		return Err(Error::usage(format!("--explain only applies to running a single day as text\n{}", USAGE)));
+	}
+	if matches!(target, Target::Watch(_)) && input == InputSource::Stdin {
+		return Err(Error::usage(format!("'watch' needs an input file to watch, not stdin\n{}", USAGE)));
+	}
+	if interval.is_some() && !matches!(target, Target::Watch(_)) {
+		return Err(Error::usage(format!("--interval only applies to 'watch'\n{}", USAGE)));
+	}
+	if format != Format::Text && matches!(target, Target::Verify | Target::Bench | Target::New(_) | Target::Generate(_) | Target::Render(_) | Target::Watch(_)) {
+		return Err(Error::usage(format!("--format only applies to running days\n{}", USAGE)));
+	}
+	if timeout.is_some() && !matches!(target, Target::Day(_) | Target::All | Target::Verify | Target::Watch(_)) {
+		return Err(Error::usage(format!("--timeout only applies to running and verifying days\n{}", USAGE)));
+	}
+	if jobs.is_some() && target != Target::All {
+		return Err(Error::usage(format!("--jobs only applies to 'all'\n{}", USAGE)));
+	}
+	let runs = runs.unwrap_or(DEFAULT_RUNS);
+	let interval = interval.unwrap_or(DEFAULT_INTERVAL);
+	let threshold = threshold.unwrap_or(DEFAULT_THRESHOLD);
+	let seed = seed.unwrap_or_default();
+	let year = year.unwrap_or_else(latest_year);
+	Ok(Args { target, year, part, input, record, title, format, runs, threshold, seed, scale, output, explain, timeout, jobs, interval })
+}
+
+fn print_event(event: &Event) {
+	println!("  {}", event);
+}
+
+// runs f with a deadline the solvers check as they go, so a day that would spin forever reports where it gave up instead
+fn with_timeout<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> T {
+	match timeout {
+		Some(timeout) => with_cancellation(&CancelToken::with_timeout(timeout), f),
+		None => f(),
+	}
+}
+
+fn run_day(year: u16, day: u8, part: Option<u8>, source: &InputSource, explain: Option<Verbosity>, timeout: Option<Duration>) -> Result<()> {
+	let solution = get_day(year, day)?;
+	let input_file = start_day(year, day, source)?;
+
+	// explained parts run one at a time, each printing its events under its own heading
+	if let Some(verbosity) = explain {
+		let parsed = solution.parse(input_file.as_str())?;
+		for current in [1, 2].into_iter().filter(|&current| part.is_none_or(|part| part == current)) {
+			if current == 2 && part.is_none() {
+				println!();
+			}
+			println!("=== Part {} ===", current);
+			let result = with_timeout(timeout, || trace(verbosity, print_event, || match current {
+				1 => solution.solve_part1(&parsed),
+				_ => solution.solve_part2(&parsed),
+			}));
+			println!("Result = {}", result?);
+		}
+		return Ok(());
+	}
+
+	// both parts go through run, so any work they share is done once and counted apart from either part
+	let (parts, shared) = match part {
+		Some(part) => {
+			let parsed = solution.parse(input_file.as_str())?;
+			let (result, allocs) = count_allocations(|| with_timeout(timeout, || match part {
+				1 => solution.solve_part1(&parsed),
+				_ => solution.solve_part2(&parsed),
+			}));
+			(vec![(part, result, allocs)], None)
+		},
+		None => {
+			let run = with_timeout(timeout, || solution.run(input_file.as_str()))?;
+			let shared = (!run.shared.is_zero()).then_some(run.shared_allocs);
+			let DayRun { part1, part2, .. } = run;
+			(vec![(1, part1.answer, part1.allocs), (2, part2.answer, part2.allocs)], shared)
+		},
+	};
+
+	if let Some(allocs) = shared {
+		println!("=== Shared ===");
+		println!("Memory = {}", format_allocs(allocs));
+		println!();
+	}
+
+	for (ix, (part, result, allocs)) in parts.into_iter().enumerate() {
+		if ix > 0 {
+			println!();
+		}
+		println!("=== Part {} ===", part);
+		println!("Result = {}", result?);
+		println!("Memory = {}", format_allocs(allocs));
+	}
+
+	Ok(())
+}
+
+fn format_duration(duration: Duration) -> String {
+	let micros = duration.as_secs_f64() * 1_000_000.0;
+	if micros < 1_000.0 {
+		format!("{:.1}µs", micros)
+	} else if micros < 1_000_000.0 {
+		format!("{:.2}ms", micros / 1_000.0)
+	} else {
+		format!("{:.2}s", micros / 1_000_000.0)
+	}
+}
+
+fn format_bytes(bytes: u64) -> String {
+	match bytes {
+		0..1_024 => format!("{}B", bytes),
+		1_024..1_048_576 => format!("{:.1}KiB", bytes as f64 / 1_024.0),
+		1_048_576..1_073_741_824 => format!("{:.1}MiB", bytes as f64 / 1_048_576.0),
+		_ => format!("{:.2}GiB", bytes as f64 / 1_073_741_824.0),
+	}
+}
+
+fn format_allocs(allocs: AllocStats) -> String {
+	format!("{} allocations, {} allocated, {} peak", allocs.allocations, format_bytes(allocs.bytes), format_bytes(allocs.peak_bytes))
+}
+
+fn format_answer(part: &PartRun) -> String {
+	match &part.answer {
+		Ok(answer) => answer.to_string(),
+		Err(_) => String::from("error"),
+	}
+}
+
+type YearRun = Vec<(&'static dyn Day, Result<DayRun>)>;
+
+// every day of a year, in day order, with up to jobs days and both of their parts running at once
+fn run_days(year: u16, source: &InputSource, timeout: Option<Duration>, jobs: Option<usize>) -> Result<YearRun> {
+	let jobs = jobs.unwrap_or_else(default_jobs);
+	Ok(parallel_map(get_year(year)?.days, jobs, |&solution| {
+		let run = read_input(year, solution.day(), source).and_then(|input| with_timeout(timeout, || match jobs {
+			// one job runs everything in turn, for timings that aren't competing for cores
+			1 => solution.run(&input),
+			_ => solution.run_concurrent(&input),
+		}));
+		(solution, run)
+	}))
+}
+
+fn run_all(year: u16, source: &InputSource, timeout: Option<Duration>, jobs: Option<usize>) -> Result<()> {
+	println!("Advent of Code {} - All Days", year);
+
+	let start = Instant::now();
+	let runs = run_days(year, source, timeout, jobs)?;
+	let wall_time = start.elapsed();
+	let total_time = runs.iter()
+		.filter_map(|(_, run)| run.as_ref().ok())
+		.map(|run| run.parse + run.solve())
+		.sum::<Duration>();
+
+	println!(
+		"{:<4} {:<20} {:>16} {:>16} {:>10} {:>10} {:>10} {:>10} {:>10}",
+		"Day", "Title", "Part 1", "Part 2", "Parse", "Solve", "Allocs", "Bytes", "Peak"
+	);
+	let mut errors = vec![];
+	for (solution, run) in &runs {
+		match run {
+			Ok(run) => {
+				let allocs = run.allocs();
+				println!(
+					"{:<4} {:<20} {:>16} {:>16} {:>10} {:>10} {:>10} {:>10} {:>10}",
+					format!("{:0>2}", solution.day()), solution.title(),
+					format_answer(&run.part1), format_answer(&run.part2),
+					format_duration(run.parse), format_duration(run.solve()),
+					allocs.allocations, format_bytes(allocs.bytes), format_bytes(allocs.peak_bytes)
+				);
+				for (part, run) in [(1, &run.part1), (2, &run.part2)] {
+					if let Err(err) = &run.answer {
+						errors.push(format!("day {:0>2} part {}: {}", solution.day(), part, err));
+					}
+				}
+			},
+			Err(err) => {
+				println!(
+					"{:<4} {:<20} {:>16} {:>16} {:>10} {:>10} {:>10} {:>10} {:>10}",
+					format!("{:0>2}", solution.day()), solution.title(), "-", "-", "-", "-", "-", "-", "-"
+				);
+				errors.push(format!("day {:0>2}: {}", solution.day(), err));
+			},
+		}
+	}
+	println!("Total wall time: {} ({} across every day)", format_duration(wall_time), format_duration(total_time));
+
+	// the same days again, split into the stages their time and memory went to
+	println!();
+	println!("{:<4} {:<6} {:>10} {:>10} {:>10} {:>10}", "Day", "Stage", "Time", "Allocs", "Bytes", "Peak");
+	for (solution, run) in &runs {
+		let Ok(run) = run else { continue };
+		let stages = [
+			(Stage::Parse, run.parse, run.parse_allocs),
+			(Stage::Shared, run.shared, run.shared_allocs),
+			(Stage::Part1, run.part1.elapsed, run.part1.allocs),
+			(Stage::Part2, run.part2.elapsed, run.part2.allocs),
+		];
+		for (stage, elapsed, allocs) in stages.into_iter().filter(|&(stage, elapsed, _)| stage != Stage::Shared || !elapsed.is_zero()) {
+			println!(
+				"{:<4} {:<6} {:>10} {:>10} {:>10} {:>10}",
+				format!("{:0>2}", solution.day()), stage, format_duration(elapsed),
+				allocs.allocations, format_bytes(allocs.bytes), format_bytes(allocs.peak_bytes)
+			);
+		}
+	}
+
+	if !errors.is_empty() {
+		println!();
+		errors.iter().for_each(|err| println!("{}", err));
+	}
+
+	Ok(())
+}
+
+fn verify(year: u16, source: &InputSource, record: bool, timeout: Option<Duration>) -> Result<()> {
+	let days = get_year(year)?.days;
+	let path = answers_file(year, source).ok_or_else(|| Error::usage(format!("no answers file for {:?}", source)))?;
+	let mut store = AnswerStore::load(&path)?;
+	println!("Advent of Code {} - Verifying against {}", year, path.display());
+	println!("{:<4} {:<4} {:<10} Answer", "Day", "Part", "Status");
+
+	let (mut correct, mut incorrect, mut unknown, mut failed, mut recorded) = (0, 0, 0, 0, 0);
+	for solution in days {
+		let day = solution.day();
+		let run = match read_input(year, day, source).and_then(|input| with_timeout(timeout, || solution.run(&input))) {
+			Ok(run) => run,
+			// days without an input and without recorded answers have nothing to verify, but any other failure counts
+			Err(Error::InputNotFound { .. }) if store.get(day, 1).is_none() && store.get(day, 2).is_none() => continue,
+			Err(err) => {
+				println!("{:<4} {:<4} {:<10} {}", format!("{:0>2}", day), "-", "error", err);
+				failed += 1;
+				continue;
+			},
+		};
+
+		let DayRun { part1, part2, .. } = run;
+		for (part, run) in [(1, part1), (2, part2)] {
+			let (status, detail) = match run.answer {
+				Ok(answer) => match store.check(day, part, &answer) {
+					Verdict::Correct => {
+						correct += 1;
+						("ok", answer.to_string())
+					},
+					Verdict::Incorrect { expected } => {
+						incorrect += 1;
+						("MISMATCH", format!("{} (expected {})", answer, expected))
+					},
+					Verdict::Unknown if record => {
+						recorded += 1;
+						let detail = answer.to_string();
+						store.insert(day, part, answer);
+						("recorded", detail)
+					},
+					Verdict::Unknown => {
+						unknown += 1;
+						("unknown", answer.to_string())
+					},
+				},
+				// a failing part fails verify even when there's no answer to compare it with yet
+				Err(err) => {
+					failed += 1;
+					("error", err.to_string())
+				},
+			};
+			println!("{:<4} {:<4} {:<10} {}", format!("{:0>2}", day), part, status, detail);
+		}
+	}
+
+	if recorded > 0 {
+		store.save(&path)?;
+	}
+
+	println!();
+	println!("{} ok, {} mismatched, {} failed, {} without an expected answer, {} recorded", correct, incorrect, failed, unknown, recorded);
+	if incorrect + failed > 0 {
+		return Err(Error::failed(format!("{} answers did not verify", incorrect + failed)));
+	}
+	Ok(())
+}
+
+fn bench(year: u16, source: &InputSource, runs: usize, threshold: u32, record: bool) -> Result<()> {
+	let days = get_year(year)?.days;
+	let path = baseline_file(year, source).ok_or_else(|| Error::usage(format!("no baseline file for {:?}", source)))?;
+	let mut baseline = Baseline::load(&path)?;
+	println!("Advent of Code {} - Median of {} runs against {}", year, runs, path.display());
+
+	// a day or part that fails can't be timed, and is flagged rather than left out, since that would look like no change
+	let mut current = Baseline::default();
+	let mut failures = vec![];
+	for solution in days {
+		let res = read_input(year, solution.day(), source)
+			.and_then(|input| current.measure(*solution, &input, runs));
+		match res {
+			Ok(failed) => failures.extend(failed.into_iter().map(|(stage, err)| (solution.day(), stage.to_string(), err))),
+			Err(err @ Error::InputNotFound { .. }) => println!("skipping day {:0>2}: {}", solution.day(), err),
+			Err(err) => failures.push((solution.day(), String::from("-"), err)),
+		}
+	}
+
+	println!("{:<4} {:<6} {:>10} {:>10} {:>10}", "Day", "Part", "Baseline", "Current", "Change");
+	for (day, stage, err) in &failures {
+		println!("{:<4} {:<6} {:>10} {:>10} {:>10} {}", format!("{:0>2}", day), stage, "-", "-", "FAILED", err);
+	}
+	let mut regressions = 0;
+	for (day, stage, median) in current.iter() {
+		let (previous, change) = match baseline.get(day, stage) {
+			Some(previous) => {
+				let comparison = Comparison { day, stage, baseline: previous, current: median };
+				let regressed = comparison.is_regression(threshold as f64);
+				if regressed {
+					regressions += 1;
+				}
+				let marker = if regressed { " REGRESSION" } else { "" };
+				(format_duration(previous), format!("{:+.1}%{}", comparison.change(), marker))
+			},
+			None => (String::from("-"), String::from("new")),
+		};
+		println!("{:<4} {:<6} {:>10} {:>10} {:>10}", format!("{:0>2}", day), stage, previous, format_duration(median), change);
+	}
+
+	if record {
+		baseline.merge(&current);
+		baseline.save(&path)?;
+		println!();
+		println!("Recorded baseline to {}", path.display());
+	}
+
+	if !failures.is_empty() {
+		return Err(Error::failed(format!("{} days or parts failed", failures.len())));
+	}
+	if record {
+		return Ok(());
+	}
+	if regressions > 0 {
+		return Err(Error::failed(format!("{} parts regressed by more than {}%", regressions, threshold)));
+	}
+	Ok(())
+}
+
+fn new_day(year: u16, day: u8, title: Option<&str>) -> Result<()> {
+	// scaffolding edits the source tree this runner was built from
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let paths = scaffold_day(root, year, day, title.unwrap_or("TITLE"))?;
+
+	println!("Scaffolded day {:0>2} of {}:", day, year);
+	paths.iter().for_each(|path| println!("  {}", path.strip_prefix(root).unwrap_or(path).display()));
+	Ok(())
+}
+
+fn generate(year: u16, day: u8, seed: u64, scale: Option<usize>) -> Result<()> {
+	let scale = match scale {
+		Some(scale) => scale,
+		None => default_scale(year, day)?,
+	};
+	println!("{}", generate_input(year, day, seed, scale)?);
+	Ok(())
+}
+
+// ascii to the terminal, or to a file in whichever format its extension names
+fn render(year: u16, day: u8, source: &InputSource, output: Option<&Path>) -> Result<()> {
+	let solution = get_day(year, day)?;
+	let parsed = solution.parse(&read_input(year, day, source)?)?;
+	let scene = solution.render(&parsed).ok_or_else(|| Error::usage(format!("day {:0>2} has nothing to render", day)))?;
+
+	let Some(path) = output else {
+		println!("{}", scene.to_ascii());
+		return Ok(());
+	};
+	let contents = match path.extension().and_then(|ext| ext.to_str()) {
+		Some("ppm") => scene.to_ppm(),
+		Some("svg") => scene.to_svg().into_bytes(),
+		_ => scene.to_ascii().into_bytes(),
+	};
+	fs::write(path, contents).map_err(|err| Error::failed(format!("could not write {}: {}", path.display(), err)))?;
+	println!("Rendered day {:0>2} to {}", day, path.display());
+	Ok(())
+}
+
+// something watch re-runs whenever any of its files change, with what it answered last time to compare against
+struct Watched {
+	label: String,
+	source: InputSource,
+	answers: Box<dyn Fn() -> Result<AnswerStore>>,
+	watcher: Watcher,
+	previous: [Option<String>; 2],
+}
+
+// a solver that panics part way through an edit shouldn't take the watch down with it
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+	panic::catch_unwind(panic::AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+		let message = payload.downcast_ref::<&str>().map(|message| message.to_string())
+			.or_else(|| payload.downcast_ref::<String>().cloned())
+			.unwrap_or_default();
+		Err(Error::failed(format!("panicked: {}", message)))
+	})
+}
+
+fn rerun(solution: &dyn Day, part: Option<u8>, watched: &mut Watched, timeout: Option<Duration>) {
+	println!("=== {} ===", watched.label);
+	let expected = match (watched.answers)() {
+		Ok(expected) => Some(expected),
+		Err(err) => {
+			println!("  {}", err);
+			None
+		},
+	};
+	// a parse error is shown once rather than for every part, and parts compare against it as just "error"
+	let parsed = match read_input(solution.year(), solution.day(), &watched.source).and_then(|input| catch_panic(|| solution.parse(&input))) {
+		Ok(parsed) => Some(parsed),
+		Err(err) => {
+			println!("{}", err);
+			None
+		},
+	};
+
+	for current in [1, 2].into_iter().filter(|&current| part.is_none_or(|part| part == current)) {
+		let answer = parsed.as_ref().map(|parsed| catch_panic(|| with_timeout(timeout, || match current {
+			1 => solution.solve_part1(parsed),
+			_ => solution.solve_part2(parsed),
+		})));
+		let description = match &answer {
+			Some(Ok(answer)) => answer.to_string(),
+			Some(Err(err)) => format!("error: {}", err),
+			None => String::from("error"),
+		};
+		let change = match &watched.previous[current as usize - 1] {
+			None => String::new(),
+			Some(previous) if *previous == description => String::from(" (unchanged)"),
+			Some(previous) => format!(" (was {})", previous),
+		};
+		let verdict = match (&answer, &expected) {
+			(Some(Ok(answer)), Some(expected)) => match expected.check(solution.day(), current, answer) {
+				Verdict::Correct => String::from(" [correct]"),
+				Verdict::Incorrect { expected } => format!(" [expected {}]", expected),
+				Verdict::Unknown => String::new(),
+			},
+			_ => String::new(),
+		};
+		println!("Part {} = {}{}{}", current, description, change, verdict);
+		watched.previous[current as usize - 1] = Some(description);
+	}
+}
+
+// re-runs a day against its input and each of its examples whenever one of them is edited, until interrupted
+fn watch(year: u16, day: u8, part: Option<u8>, source: &InputSource, timeout: Option<Duration>, interval: Duration) -> Result<()> {
+	let solution = get_day(year, day)?;
+	let watched = |label: String, source: InputSource, answers: Box<dyn Fn() -> Result<AnswerStore>>, paths: Vec<PathBuf>| Watched {
+		label, source, answers, watcher: Watcher::new(paths), previous: [None, None],
+	};
+
+	// the real input is checked against the answers verify records, when it has any
+	let mut paths = input_candidates(year, day, source);
+	let answers: Box<dyn Fn() -> Result<AnswerStore>> = match answers_file(year, source) {
+		Some(path) => {
+			paths.push(path.clone());
+			Box::new(move || AnswerStore::load(&path))
+		},
+		None => Box::new(|| Ok(AnswerStore::default())),
+	};
+	let mut targets = vec![watched(String::from("input"), source.clone(), answers, paths)];
+	let examples = discover_examples(&examples_dir())?;
+	for example in examples.into_iter().filter(|example| (example.year, example.day) == (year, day)) {
+		let answers = example.input.with_extension("answers");
+		let paths = vec![example.input.clone(), answers.clone()];
+		targets.push(watched(example.name, InputSource::File(example.input), Box::new(move || AnswerStore::load_for_day(&answers, day)), paths));
+	}
+
+	println!("Advent of Code {} - Watching Day {:0>2}: {}", year, day, solution.title());
+	targets.iter().flat_map(|target| target.watcher.paths()).for_each(|path| println!("  {}", path.display()));
+	for target in &mut targets {
+		println!();
+		rerun(solution, part, target, timeout);
+	}
+
+	loop {
+		thread::sleep(interval);
+		for target in &mut targets {
+			let changed = target.watcher.poll();
+			if let Some(path) = changed.first() {
+				println!();
+				println!("{} changed", path.display());
+				rerun(solution, part, target, timeout);
+			}
+		}
+	}
+}
+
+fn print_records(records: &[Record], format: Format) {
+	match format {
+		Format::Json => println!("{}", to_json(records)),
+		Format::Csv => println!("{}", to_csv(records)),
+		Format::Text => unreachable!("text output is printed as the days run"),
+	}
+}
+
+fn report_day(year: u16, day: u8, part: Option<u8>, source: &InputSource, format: Format, timeout: Option<Duration>) -> Result<()> {
+	let solution = get_day(year, day)?;
+	let run = read_input(year, day, source).and_then(|input| with_timeout(timeout, || solution.run(&input)));
+	let records = records(solution, run).into_iter()
+		.filter(|record| match part {
+			Some(1) => record.stage != Stage::Part2,
+			Some(_) => record.stage != Stage::Part1,
+			None => true,
+		})
+		.collect::<Vec<_>>();
+
+	print_records(&records, format);
+	Ok(())
+}
+
+fn report_all(year: u16, source: &InputSource, format: Format, timeout: Option<Duration>, jobs: Option<usize>) -> Result<()> {
+	let records = run_days(year, source, timeout, jobs)?.into_iter()
+		.flat_map(|(solution, run)| records(solution, run))
+		.collect::<Vec<_>>();
+
+	print_records(&records, format);
+	Ok(())
+}
+
+fn run(args: Args) -> Result<()> {
+	match args.target {
+		Target::Day(day) if args.format == Format::Text => run_day(args.year, day, args.part, &args.input, args.explain, args.timeout),
+		Target::Day(day) => report_day(args.year, day, args.part, &args.input, args.format, args.timeout),
+		Target::All if args.format == Format::Text => run_all(args.year, &args.input, args.timeout, args.jobs),
+		Target::All => report_all(args.year, &args.input, args.format, args.timeout, args.jobs),
+		Target::Verify => verify(args.year, &args.input, args.record, args.timeout),
+		Target::Bench => bench(args.year, &args.input, args.runs, args.threshold, args.record),
+		Target::New(day) => new_day(args.year, day, args.title.as_deref()),
+		Target::Generate(day) => generate(args.year, day, args.seed, args.scale),
+		Target::Render(day) => render(args.year, day, &args.input, args.output.as_deref()),
+		Target::Watch(day) => watch(args.year, day, args.part, &args.input, args.timeout, args.interval),
+		Target::Help => {
+			println!("{}", USAGE);
+			Ok(())
+		},
+	}
+}
+
+pub fn main() -> ExitCode {
+	match parse_args(env::args().skip(1)).and_then(run) {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(err) => {
+			eprintln!("{}", err);
+			ExitCode::FAILURE
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn args(line: &str) -> Result<Args> {
+		parse_args(line.split_whitespace().map(String::from))
+	}
+
+	#[test]
+	fn test_parse_args() -> Result<()> {
+		assert_eq!(Args { target: Target::Day(1), ..Args::default() }, args("1")?);
+		assert_eq!(Args { target: Target::Day(8), part: Some(2), input: InputSource::Stdin, ..Args::default() }, args("08 --part 2 --input -")?);
+		assert_eq!(Args { target: Target::Day(11), part: Some(1), input: InputSource::File(PathBuf::from("example.txt")), ..Args::default() }, args("-i example.txt 11 -p 1")?);
+		assert_eq!(Args { target: Target::All, ..Args::default() }, args("all")?);
+		assert_eq!(Args { target: Target::All, input: InputSource::Dir(PathBuf::from("inputs")), ..Args::default() }, args("all --input-dir inputs")?);
+		assert_eq!(Args { target: Target::Verify, record: true, ..Args::default() }, args("verify --record")?);
+		assert_eq!(Args { target: Target::New(12), title: Some(String::from("Farm")), ..Args::default() }, args("new 12 --title Farm")?);
+		assert_eq!(Args { target: Target::All, format: Format::Csv, ..Args::default() }, args("all --format csv")?);
+		assert_eq!(Args { target: Target::Bench, record: true, runs: 5, threshold: 20, ..Args::default() }, args("bench --runs 5 --threshold 20% --record")?);
+		assert_eq!(Args { target: Target::Generate(9), seed: 42, scale: Some(50), ..Args::default() }, args("generate 9 --seed 42 --scale 50")?);
+		assert_eq!(Args { target: Target::Render(9), output: Some(PathBuf::from("day09.svg")), ..Args::default() }, args("render 9 -o day09.svg")?);
+		assert_eq!(Args { target: Target::Day(5), part: Some(2), explain: Some(Verbosity::Detail), ..Args::default() }, args("5 --explain=detail -p 2")?);
+		assert_eq!(Some(Verbosity::Summary), args("11 --explain")?.explain);
+		assert_eq!(Some(Duration::from_millis(2500)), args("10 --timeout 2.5")?.timeout);
+		assert_eq!(Some(Duration::from_secs(30)), args("verify -T 30")?.timeout);
+		assert!(args("10 --timeout 0").is_err());
+		assert!(args("10 --timeout soon").is_err());
+		assert!(args("bench --timeout 5").is_err());
+		assert_eq!(2024, args("--year 2024 all")?.year);
+		assert_eq!(2026, args("new 1 -y 2026 --title Lobby")?.year);
+		assert!(args("1 --year twenty").is_err());
+		assert_eq!(Some(4), args("all -j 4")?.jobs);
+		assert!(args("all --jobs 0").is_err());
+		assert!(args("9 --jobs 2").is_err());
+		assert_eq!(Args { target: Target::Watch(8), part: Some(1), input: InputSource::File(PathBuf::from("08.txt")), interval: Duration::from_millis(200), ..Args::default() }, args("watch 8 -p 1 -i 08.txt --interval 200")?);
+		assert!(args("watch 8 --input -").is_err());
+		assert!(args("8 --interval 200").is_err());
+		assert!(args("watch 8 --format json").is_err());
+		assert!(args("11 --explain=everything").is_err());
+		assert!(args("all --explain").is_err());
+		assert!(args("11 --explain --format json").is_err());
+		assert!(args("render 4 --part 1").is_err());
+		assert!(args("4 --output day04.ppm").is_err());
+		assert!(args("generate 9 --scale 0").is_err());
+		assert!(args("1 --seed 42").is_err());
+		assert!(args("generate 9 --input 09.txt").is_err());
+		assert!(args("all --part 1").is_err());
+		assert!(args("bench --runs 0").is_err());
+		assert!(args("all --runs 5").is_err());
+		assert!(args("1 --format yaml").is_err());
+		assert!(args("new").is_err());
+		assert!(args("1 --title Farm").is_err());
+		assert!(args("verify --input 01.txt").is_err());
+		assert!(args("1 --record").is_err());
+		assert!(args("all --input 01.txt").is_err());
+		assert!(args("--part 3 1").is_err());
+		assert!(args("").is_err());
+		// asking for help isn't a mistake, however the rest of the line looks
+		assert_eq!(Target::Help, args("--help")?.target);
+		assert_eq!(Target::Help, args("3 --part 1 -h")?.target);
+		Ok(())
+	}
+
+	#[test]
+	fn test_catch_panic() {
+		assert_eq!(3, catch_panic(|| Ok(3)).unwrap());
+		let err = catch_panic::<()>(|| panic!("index out of bounds")).unwrap_err();
+		assert_eq!("panicked: index out of bounds", err.to_string());
+	}
+}
\ No newline at end of file
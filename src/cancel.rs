@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::{Error, Result};
+
+// shared between the thread solving and whoever might want to stop it, so clones all cancel together
+#[derive(Debug, Clone)]
+pub struct CancelToken {
+	cancelled: Arc<AtomicBool>,
+	started: Instant,
+	deadline: Option<Instant>,
+}
+
+impl CancelToken {
+	pub fn new() -> Self {
+		Self { cancelled: Arc::new(AtomicBool::new(false)), started: Instant::now(), deadline: None }
+	}
+
+	pub fn with_timeout(timeout: Duration) -> Self {
+		let token = Self::new();
+		Self { deadline: Some(token.started + timeout), ..token }
+	}
+
+	pub fn cancel(&self) {
+		self.cancelled.store(true, Ordering::Relaxed);
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+	}
+
+	fn error(&self, location: String) -> Error {
+		if self.cancelled.load(Ordering::Relaxed) {
+			Error::Cancelled { location }
+		} else {
+			Error::TimedOut { elapsed: self.started.elapsed(), location }
+		}
+	}
+}
+
+impl Default for CancelToken {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+// solvers never see the token directly, they check whichever one the runner installed on their thread
+thread_local! {
+	static TOKEN: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+struct Restore(Option<CancelToken>);
+impl Drop for Restore {
+	fn drop(&mut self) {
+		TOKEN.set(self.0.take());
+	}
+}
+
+// runs f with checks on this thread answering to token
+pub fn with_cancellation<T>(token: &CancelToken, f: impl FnOnce() -> T) -> T {
+	let _restore = Restore(TOKEN.replace(Some(token.clone())));
+	f()
+}
+
+// wraps f to answer to whichever token this thread is answering to, for handing work to another thread
+pub fn propagate<T>(f: impl FnOnce() -> T) -> impl FnOnce() -> T {
+	let token = TOKEN.with(|token| token.borrow().clone());
+	move || match token {
+		Some(token) => with_cancellation(&token, f),
+		None => f(),
+	}
+}
+
+// for loops that can't return an error themselves, like the successors of a search
+pub fn cancelled() -> bool {
+	TOKEN.with(|token| token.borrow().as_ref().is_some_and(CancelToken::is_cancelled))
+}
+
+// an error saying where the solver had got to once it's been cancelled, so it can stop with ?
+pub fn check(location: impl FnOnce() -> String) -> Result<()> {
+	TOKEN.with(|token| match token.borrow().as_ref() {
+		Some(token) if token.is_cancelled() => Err(token.error(location())),
+		_ => Ok(()),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cancel::*;
+
+	#[test]
+	fn test_check() {
+		// nothing to answer to outside of with_cancellation
+		assert!(check(|| unreachable!()).is_ok());
+
+		let token = CancelToken::new();
+		with_cancellation(&token, || {
+			assert!(check(|| String::from("step 1")).is_ok());
+			token.clone().cancel();
+			assert!(cancelled());
+			assert_eq!("cancelled on step 2", check(|| String::from("step 2")).unwrap_err().to_string());
+		});
+		assert!(!cancelled());
+	}
+
+	#[test]
+	fn test_propagate() {
+		let token = CancelToken::new();
+		token.cancel();
+		let other_thread = with_cancellation(&token, || std::thread::spawn(propagate(cancelled)));
+		assert!(other_thread.join().unwrap());
+		assert!(!std::thread::spawn(propagate(cancelled)).join().unwrap());
+	}
+
+	#[test]
+	fn test_timeout() {
+		let token = CancelToken::with_timeout(Duration::ZERO);
+		let err = with_cancellation(&token, || check(|| String::from("machine #3")).unwrap_err());
+		assert!(matches!(&err, Error::TimedOut { location, .. } if location == "machine #3"));
+		assert!(err.to_string().starts_with("timed out after 0.0 s on machine #3"));
+	}
+}
\ No newline at end of file
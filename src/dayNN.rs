@@ -1,30 +1,59 @@
 #![allow(unused_imports)]
-use anyhow::*;
 use itertools::Itertools;
+use crate::{Answer, Error, Result, Solution};
+use crate::parse::{parse_complete, IResult, ParseDiagnostic};
 
-pub fn part1(input: &str) -> Result<u64> {
-	let _ = input;
-	Ok(0)
+fn parse(input: &str) -> Result<Vec<String>> {
+	Ok(input.lines().map(String::from).collect())
 }
 
-pub fn part2(input: &str) -> Result<u64> {
-	let _ = input;
-	Ok(0)
+pub struct DayNN;
+
+impl Solution for DayNN {
+	const YEAR: u16 = 0;
+	const DAY: u8 = 0;
+	const TITLE: &'static str = "TITLE";
+
+	type Input = Vec<String>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(input: &Self::Input) -> Result<Answer> {
+		let _ = input;
+		Err(Error::no_solution("part 1 hasn't been solved yet"))
+	}
+
+	fn part2(input: &Self::Input) -> Result<Answer> {
+		let _ = input;
+		Err(Error::no_solution("part 2 hasn't been solved yet"))
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	DayNN::part1(&DayNN::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	DayNN::part2(&DayNN::parse(input)?)
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::dayNN::*;
+	use crate::yYYYY::dayNN::*;
 
-	const TEST: &str = "TEST-INPUT";
+	const TEST: &str = include_str!("../../tests/examples/YYYY/NN/example.txt");
 
 	#[test]
+	#[ignore = "the example and its answer haven't been filled in yet"]
 	fn test_part_one() -> Result<()> {
 		assert_eq!(0, part1(TEST)?);
 		Ok(())
 	}
 
 	#[test]
+	#[ignore = "the example and its answer haven't been filled in yet"]
 	fn test_part_two() -> Result<()> {
 		assert_eq!(0, part2(TEST)?);
 		Ok(())
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::parse::ParseDiagnostic;
+
+#[derive(Debug)]
+pub enum Error {
+	Io {
+		path: Option<PathBuf>,
+		source: io::Error,
+	},
+	InputNotFound {
+		year: u16,
+		day: u8,
+		tried: Vec<PathBuf>,
+		// the day's input in the old layout, with no year directory
+		misplaced: Option<PathBuf>,
+	},
+	Parse(ParseDiagnostic),
+	NoSolution(String),
+	Overflow(String),
+	UnknownYear(u16),
+	UnknownDay {
+		year: u16,
+		day: u8,
+	},
+	Scaffold(String),
+	NoGenerator {
+		year: u16,
+		day: u8,
+	},
+	// a solver stopped at a cancellation check, saying where it had got to
+	TimedOut {
+		elapsed: Duration,
+		location: String,
+	},
+	Cancelled {
+		location: String,
+	},
+	// the runner was invoked wrongly, or asked for something the day doesn't have
+	Usage(String),
+	// a runner command ran, but what it checked didn't hold up
+	Failed(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+	pub fn no_solution(reason: impl Into<String>) -> Self {
+		Error::NoSolution(reason.into())
+	}
+
+	pub fn overflow(operation: impl Into<String>) -> Self {
+		Error::Overflow(operation.into())
+	}
+
+	pub fn scaffold(reason: impl Into<String>) -> Self {
+		Error::Scaffold(reason.into())
+	}
+
+	pub fn usage(message: impl Into<String>) -> Self {
+		Error::Usage(message.into())
+	}
+
+	pub fn failed(reason: impl Into<String>) -> Self {
+		Error::Failed(reason.into())
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::Io { path: Some(path), source } => write!(f, "failed to read {}: {}", path.display(), source),
+			Error::Io { path: None, source } => write!(f, "failed to read input: {}", source),
+			Error::InputNotFound { year, day, tried, misplaced } => {
+				write!(f, "no input found for day {:0>2} of {}, tried:", day, year)?;
+				tried.iter().try_for_each(|path| write!(f, "\n  {}", path.display()))?;
+				match misplaced {
+					Some(path) => write!(f, "\nfound {} from before inputs were kept per year, move it into {}", path.display(), path.with_file_name(year.to_string()).display()),
+					None => Ok(()),
+				}
+			},
+			Error::Parse(diagnostic) => write!(f, "failed to parse input: {}", diagnostic),
+			Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+			Error::Overflow(operation) => write!(f, "arithmetic overflow: {}", operation),
+			Error::UnknownYear(year) => write!(f, "no solutions registered for {}", year),
+			Error::UnknownDay { year, day } => write!(f, "no solution registered for day {} of {}", day, year),
+			Error::Scaffold(reason) => write!(f, "cannot scaffold day: {}", reason),
+			Error::NoGenerator { year, day } => write!(f, "no input generator for day {} of {}", day, year),
+			Error::TimedOut { elapsed, location } => write!(f, "timed out after {:.1} s on {}", elapsed.as_secs_f64(), location),
+			Error::Cancelled { location } => write!(f, "cancelled on {}", location),
+			Error::Usage(message) => write!(f, "{}", message),
+			Error::Failed(reason) => write!(f, "{}", reason),
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Error::Io { source, .. } => Some(source),
+			_ => None,
+		}
+	}
+}
+
+impl From<io::Error> for Error {
+	fn from(source: io::Error) -> Self {
+		Error::Io { path: None, source }
+	}
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use crate::{AnswerStore, Error, Result};
+
+pub const EXAMPLES_DIR: &str = "tests/examples";
+
+// one example input, tests/examples/YYYY/NN/<name>.txt, with its expected answers in <name>.answers
+#[derive(Debug, Clone)]
+pub struct Example {
+	pub year: u16,
+	pub day: u8,
+	pub name: String,
+	pub input: PathBuf,
+	pub answers: AnswerStore,
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+	let entries = fs::read_dir(dir).map_err(|source| Error::Io { path: Some(dir.to_path_buf()), source })?;
+	let mut paths = entries.map(|entry| entry.map(|entry| entry.path()))
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|source| Error::Io { path: Some(dir.to_path_buf()), source })?;
+	paths.sort();
+	Ok(paths)
+}
+
+pub fn examples_dir() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR)
+}
+
+// the directories in dir named like a number, with that number
+fn numbered_dirs<T: FromStr>(dir: &Path) -> Result<Vec<(T, PathBuf)>> {
+	Ok(read_dir(dir)?.into_iter()
+		.filter(|path| path.is_dir())
+		.filter_map(|path| Some((path.file_name()?.to_str()?.parse().ok()?, path)))
+		.collect())
+}
+
+// every example under dir, ordered by year, then day and then name
+pub fn discover_examples(dir: &Path) -> Result<Vec<Example>> {
+	let mut examples = vec![];
+	for (year, year_dir) in numbered_dirs(dir)? {
+		for (day, day_dir) in numbered_dirs(&year_dir)? {
+			for input in read_dir(&day_dir)? {
+				if input.extension().is_none_or(|ext| ext != "txt") {
+					continue;
+				}
+				let name = input.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+				let answers = AnswerStore::load_for_day(&input.with_extension("answers"), day)?;
+				examples.push(Example { year, day, name, input, answers });
+			}
+		}
+	}
+	Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::examples::*;
+
+	#[test]
+	fn test_discover_examples() -> Result<()> {
+		let examples = discover_examples(&examples_dir())?;
+		let day11 = examples.iter().filter(|example| (example.year, example.day) == (2025, 11)).map(|example| example.name.as_str()).collect::<Vec<_>>();
+		assert_eq!(vec!["example1", "example2"], day11);
+		assert!(examples.iter().all(|example| example.input.is_file()));
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Verbosity {
+	// a handful of events per part, like merged ranges or path counts
+	Summary = 1,
+	// an event for every step, like each rotation of the dial
+	Detail = 2,
+}
+
+// something a solver did, with named values to show alongside it
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Event {
+	pub verbosity: Verbosity,
+	pub name: &'static str,
+	pub fields: Vec<(&'static str, String)>,
+}
+impl fmt::Display for Event {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.name)?;
+		self.fields.iter().try_for_each(|(key, value)| write!(f, " {}={}", key, value))
+	}
+}
+
+type Sink = Box<dyn FnMut(&Event)>;
+
+// nothing is listening until trace installs a sink on this thread, so an untraced explain! is cheap but not free: one thread-local level read per call site
+thread_local! {
+	static LEVEL: Cell<u8> = const { Cell::new(0) };
+	static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+pub fn enabled(verbosity: Verbosity) -> bool {
+	LEVEL.with(|level| verbosity as u8 <= level.get())
+}
+
+pub fn emit(event: Event) {
+	SINK.with(|sink| {
+		if let Some(sink) = sink.borrow_mut().as_mut() {
+			sink(&event);
+		}
+	});
+}
+
+// puts back whatever was tracing before, even if f panics
+struct Restore(u8, Option<Sink>);
+impl Drop for Restore {
+	fn drop(&mut self) {
+		LEVEL.set(self.0);
+		SINK.set(self.1.take());
+	}
+}
+
+// hands every event up to the given verbosity that f emits on this thread to sink, as it happens
+pub fn trace<T>(verbosity: Verbosity, sink: impl FnMut(&Event) + 'static, f: impl FnOnce() -> T) -> T {
+	let _restore = Restore(LEVEL.replace(verbosity as u8), SINK.replace(Some(Box::new(sink))));
+	f()
+}
+
+pub fn collect_events<T>(verbosity: Verbosity, f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+	let events = Rc::new(RefCell::new(vec![]));
+	let sink_events = Rc::clone(&events);
+	let res = trace(verbosity, move |event| sink_events.borrow_mut().push(event.clone()), f);
+	(res, events.take())
+}
+
+// emits an event when something is tracing at this verbosity, and otherwise only reads the level, leaving the fields unevaluated
+// explain!(Detail, "rotation", dial = dial, zero_passes = passes);
+#[macro_export]
+macro_rules! explain {
+	($verbosity:ident, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+		if $crate::explain::enabled($crate::explain::Verbosity::$verbosity) {
+			$crate::explain::emit($crate::explain::Event {
+				verbosity: $crate::explain::Verbosity::$verbosity,
+				name: $name,
+				fields: vec![$((stringify!($key), $value.to_string())),*],
+			});
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::explain::*;
+
+	#[test]
+	fn test_levels() {
+		let evaluated = Cell::new(0);
+		let step = |verbosity| collect_events(verbosity, || {
+			explain!(Summary, "summary", total = 3);
+			explain!(Detail, "detail", step = {
+				evaluated.set(evaluated.get() + 1);
+				1
+			});
+		}).1;
+
+		assert_eq!(vec!["summary total=3"], step(Verbosity::Summary).iter().map(Event::to_string).collect::<Vec<_>>());
+		assert_eq!(0, evaluated.get());
+		assert_eq!(2, step(Verbosity::Detail).len());
+		assert_eq!(1, evaluated.get());
+
+		// and nothing outside of tracing
+		explain!(Summary, "ignored", total = evaluated.get());
+		assert!(!enabled(Verbosity::Summary));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use std::ops::RangeInclusive;
+use crate::{get_day, Error, Result};
+
+// SplitMix64, small and good enough to make the same input from the same seed everywhere
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Self(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+
+	pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+		let span = range.end() - range.start();
+		match span.checked_add(1) {
+			Some(len) => range.start() + self.next_u64() % len,
+			None => self.next_u64(),
+		}
+	}
+
+	pub fn index(&mut self, len: usize) -> usize {
+		self.range(0..=(len as u64 - 1)) as usize
+	}
+
+	pub fn chance(&mut self, percent: u64) -> bool {
+		self.range(1..=100) <= percent
+	}
+
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for ix in (1..items.len()).rev() {
+			items.swap(ix, self.index(ix + 1));
+		}
+	}
+}
+
+// how a day makes up inputs, for days that have a generator
+// scale is in whatever unit suits the day, and default_scale makes an input roughly the size of a real one
+#[derive(Debug, Copy, Clone)]
+pub struct Generator {
+	pub default_scale: usize,
+	pub generate: fn(&mut Rng, usize) -> String,
+}
+
+fn generator(year: u16, day: u8) -> Result<Generator> {
+	get_day(year, day)?.generator().ok_or(Error::NoGenerator { year, day })
+}
+
+pub fn default_scale(year: u16, day: u8) -> Result<usize> {
+	Ok(generator(year, day)?.default_scale)
+}
+
+pub fn generate_input(year: u16, day: u8, seed: u64, scale: usize) -> Result<String> {
+	Ok((generator(year, day)?.generate)(&mut Rng::new(seed), scale))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::generate::*;
+	use crate::YEARS;
+
+	#[test]
+	fn test_deterministic() -> Result<()> {
+		// days without a generator yet are left out
+		for solution in YEARS.iter().flat_map(|year| year.days).filter(|solution| solution.generator().is_some()) {
+			let (year, day) = (solution.year(), solution.day());
+			assert_eq!(generate_input(year, day, 7, 20)?, generate_input(year, day, 7, 20)?, "day {:0>2} of {}", day, year);
+			assert_ne!(generate_input(year, day, 7, 20)?, generate_input(year, day, 8, 20)?, "day {:0>2} of {}", day, year);
+		}
+		assert!(matches!(generate_input(1999, 1, 7, 20), Err(Error::UnknownYear(1999))));
+		Ok(())
+	}
+
+	#[test]
+	fn test_generated_inputs_solve() -> Result<()> {
+		for solution in YEARS.iter().flat_map(|year| year.days).filter(|solution| solution.generator().is_some()) {
+			for seed in 0..3 {
+				let input = generate_input(solution.year(), solution.day(), seed, 30)?;
+				let parsed = solution.parse(&input)?;
+				let (part1, part2) = solution.solve_both(&parsed);
+				assert!(part1.is_ok(), "day {:0>2} of {} seed {}: {:?}", solution.day(), solution.year(), seed, part1);
+				assert!(part2.is_ok(), "day {:0>2} of {} seed {}: {:?}", solution.day(), solution.year(), seed, part2);
+			}
+		}
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,198 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::{Error, Result};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const ANSWERS_FILE: &str = "answers.txt";
+pub const BASELINE_FILE: &str = "baseline.txt";
+
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+	// from AOC_INPUT_DIR, holding a directory per year like input/ does
+	input_root: Option<PathBuf>,
+	input_dir: Option<PathBuf>,
+	search_from: PathBuf,
+}
+
+impl Default for InputResolver {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl InputResolver {
+	// honours AOC_INPUT_DIR, otherwise searches upwards from the working directory
+	pub fn new() -> Self {
+		Self {
+			input_root: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+			input_dir: None,
+			search_from: env::current_dir().unwrap_or_default(),
+		}
+	}
+
+	pub fn with_input_dir(mut self, input_dir: impl Into<PathBuf>) -> Self {
+		self.input_dir = Some(input_dir.into());
+		self
+	}
+
+	pub fn searching_from(mut self, dir: impl Into<PathBuf>) -> Self {
+		self.search_from = dir.into();
+		self
+	}
+
+	pub fn candidates(&self, year: u16, day: u8) -> Vec<PathBuf> {
+		let file_name = format!("{:0>2}.txt", day);
+		self.input_dirs(year).into_iter().map(|dir| dir.join(&file_name)).collect()
+	}
+
+	pub fn answers_file(&self, year: u16) -> PathBuf {
+		self.local_file(year, ANSWERS_FILE)
+	}
+
+	pub fn baseline_file(&self, year: u16) -> PathBuf {
+		self.local_file(year, BASELINE_FILE)
+	}
+
+	// files that belong to one set of inputs live next to them, in the first input directory that exists
+	fn local_file(&self, year: u16, file_name: &str) -> PathBuf {
+		let input_dirs = self.input_dirs(year);
+		let input_dir = input_dirs.iter()
+			.find(|dir| dir.is_dir())
+			.or(input_dirs.last())
+			.cloned()
+			.unwrap_or_default();
+		input_dir.join(file_name)
+	}
+
+	// a directory given explicitly already holds the inputs for the year being run
+	fn input_dirs(&self, year: u16) -> Vec<PathBuf> {
+		if let Some(input_dir) = &self.input_dir {
+			return vec![input_dir.clone()];
+		}
+		if let Some(input_root) = &self.input_root {
+			return vec![input_root.join(year.to_string())];
+		}
+
+		// look for input/<year>/ in every directory up to and including the crate root
+		let year_dir = Path::new("input").join(year.to_string());
+		let mut input_dirs = vec![];
+		for dir in self.search_from.ancestors() {
+			input_dirs.push(dir.join(&year_dir));
+			if dir.join("Cargo.toml").is_file() {
+				break;
+			}
+		}
+
+		// fall back to the crate this was built from, for binaries run from elsewhere
+		let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(year_dir);
+		if !input_dirs.contains(&manifest_dir) {
+			input_dirs.push(manifest_dir);
+		}
+
+		input_dirs
+	}
+
+	pub fn resolve(&self, year: u16, day: u8) -> Result<PathBuf> {
+		let tried = self.candidates(year, day);
+		if let Some(path) = tried.iter().find(|path| path.is_file()) {
+			return Ok(path.clone());
+		}
+
+		// inputs used to sit straight in input/ before there was more than one year, so point at any left behind
+		let misplaced = match self.input_dir {
+			Some(_) => None,
+			None => tried.iter()
+				.filter_map(|path| Some(path.parent()?.parent()?.join(path.file_name()?)))
+				.find(|path| path.is_file()),
+		};
+		Err(Error::InputNotFound { year, day, tried, misplaced })
+	}
+
+	pub fn read(&self, year: u16, day: u8) -> Result<String> {
+		read_file(self.resolve(year, day)?)
+	}
+}
+
+pub(crate) fn read_file(path: PathBuf) -> Result<String> {
+	fs::read_to_string(&path).map_err(|source| Error::Io { path: Some(path), source })
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs;
+	use crate::input::*;
+
+	fn scratch_crate(name: &str) -> PathBuf {
+		let root = env::temp_dir().join(format!("aoc25-input-{}-{}", name, std::process::id()));
+		let _ = fs::remove_dir_all(&root);
+		fs::create_dir_all(root.join("input").join("2025")).unwrap();
+		fs::create_dir_all(root.join("src").join("bin")).unwrap();
+		fs::write(root.join("Cargo.toml"), "").unwrap();
+		root
+	}
+
+	#[test]
+	fn test_resolve_from_subdirectory() -> Result<()> {
+		let root = scratch_crate("subdir");
+		fs::write(root.join("input").join("2025").join("03.txt"), "987654321111111").unwrap();
+
+		let resolver = InputResolver { input_root: None, input_dir: None, search_from: root.join("src").join("bin") };
+		assert_eq!(root.join("input").join("2025").join("03.txt"), resolver.resolve(2025, 3)?);
+		assert_eq!("987654321111111", resolver.read(2025, 3)?);
+		assert!(matches!(resolver.resolve(2024, 3), Err(Error::InputNotFound { year: 2024, .. })));
+
+		fs::remove_dir_all(root).unwrap();
+		Ok(())
+	}
+
+	#[test]
+	fn test_answers_next_to_inputs() {
+		let root = scratch_crate("answers");
+
+		let resolver = InputResolver { input_root: None, input_dir: None, search_from: root.join("src") };
+		assert_eq!(root.join("input").join("2025").join(ANSWERS_FILE), resolver.answers_file(2025));
+
+		fs::remove_dir_all(root).unwrap();
+	}
+
+	#[test]
+	fn test_missing_input_names_paths() {
+		let root = scratch_crate("missing");
+
+		let resolver = InputResolver::new().with_input_dir(root.join("elsewhere"));
+		let Err(Error::InputNotFound { day, tried, .. }) = resolver.resolve(2025, 12) else { panic!("expected a missing input") };
+		assert_eq!(12, day);
+		assert_eq!(vec![root.join("elsewhere").join("12.txt")], tried);
+
+		fs::remove_dir_all(root).unwrap();
+	}
+
+	#[test]
+	fn test_input_root_per_year() -> Result<()> {
+		let root = scratch_crate("root");
+		fs::write(root.join("input").join("2025").join("03.txt"), "987654321111111").unwrap();
+
+		let resolver = InputResolver { input_root: Some(root.join("input")), input_dir: None, search_from: env::temp_dir() };
+		assert_eq!(root.join("input").join("2025").join("03.txt"), resolver.resolve(2025, 3)?);
+		assert_eq!(root.join("input").join("2025").join(ANSWERS_FILE), resolver.answers_file(2025));
+		let Err(Error::InputNotFound { tried, .. }) = resolver.resolve(2024, 3) else { panic!("expected a missing input") };
+		assert_eq!(vec![root.join("input").join("2024").join("03.txt")], tried);
+
+		fs::remove_dir_all(root).unwrap();
+		Ok(())
+	}
+
+	#[test]
+	fn test_flat_layout_hint() {
+		let root = scratch_crate("flat");
+		fs::write(root.join("input").join("05.txt"), "3-5\n\n1").unwrap();
+
+		let resolver = InputResolver { input_root: None, input_dir: None, search_from: root.clone() };
+		let Err(err @ Error::InputNotFound { .. }) = resolver.resolve(2025, 5) else { panic!("expected a missing input") };
+		assert!(matches!(&err, Error::InputNotFound { misplaced: Some(path), .. } if *path == root.join("input").join("05.txt")));
+		assert!(err.to_string().ends_with(&format!("found {} from before inputs were kept per year, move it into {}", root.join("input").join("05.txt").display(), root.join("input").join("2025").display())));
+
+		fs::remove_dir_all(root).unwrap();
+	}
+}
\ No newline at end of file
@@ -1,20 +1,237 @@
-use std::fs;
-use anyhow::*;
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-
-pub fn start_day(day: &str) -> Result<String> {
-	println!("Advent of Code 2025 - Day {:0>2}", day);
-
-	Ok(fs::read_to_string(format!("input/{}.txt", day))?)
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+mod answer;
+mod answers;
+mod baseline;
+mod cancel;
+mod error;
+mod examples;
+pub mod explain;
+mod generate;
+mod input;
+mod memory;
+mod normalize;
+mod parallel;
+pub mod parse;
+mod render;
+mod report;
+mod scaffold;
+mod solution;
+mod watch;
+pub use answer::Answer;
+pub use answers::{AnswerStore, Verdict};
+pub use baseline::{Baseline, Comparison};
+pub use cancel::{cancelled, check, with_cancellation, CancelToken};
+pub use error::{Error, Result};
+pub use examples::{discover_examples, examples_dir, Example, EXAMPLES_DIR};
+pub use explain::{collect_events, trace, Event, Verbosity};
+pub use generate::{default_scale, generate_input, Generator, Rng};
+pub use input::{InputResolver, ANSWERS_FILE, BASELINE_FILE, INPUT_DIR_VAR};
+pub use memory::{count_allocations, AllocStats, CountingAllocator};
+pub use normalize::Normalization;
+pub use parallel::{default_jobs, parallel_map};
+pub use render::{Color, Render, Scene, Shape};
+pub use report::{records, to_csv, to_json, Record, Stage};
+pub use scaffold::scaffold_day;
+pub use solution::{both_parts, shared, Day, DayRun, Parsed, PartRun, Solution};
+pub use watch::Watcher;
+
+pub mod y2025;
+
+// every year with solutions, oldest first
+pub struct Year {
+	pub year: u16,
+	pub days: &'static [&'static dyn Day],
+}
+
+pub static YEARS: &[Year] = &[
+	Year { year: 2025, days: y2025::DAYS },
+];
+
+// what the runner works on when it isn't given a year
+pub fn latest_year() -> u16 {
+	YEARS.last().map_or(0, |year| year.year)
+}
+
+pub fn get_year(year: u16) -> Result<&'static Year> {
+	YEARS.iter()
+		.find(|registered| registered.year == year)
+		.ok_or(Error::UnknownYear(year))
+}
+
+pub fn get_day(year: u16, day: u8) -> Result<&'static dyn Day> {
+	get_year(year)?.days.iter()
+		.find(|solution| solution.day() == day)
+		.copied()
+		.ok_or(Error::UnknownDay { year, day })
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub enum InputSource {
+	#[default]
+	Default,
+	Dir(PathBuf),
+	File(PathBuf),
+	Stdin,
+}
+
+pub fn start_day(year: u16, day: u8, source: &InputSource) -> Result<String> {
+	let solution = get_day(year, day)?;
+	println!("Advent of Code {} - Day {:0>2}: {}", year, day, solution.title());
+
+	read_input(year, day, source)
+}
+
+// loads the input for a day and applies that day's normalization to it
+pub fn read_input(year: u16, day: u8, source: &InputSource) -> Result<String> {
+	let raw_input = read_raw_input(year, day, source)?;
+	Ok(get_day(year, day)?.normalization().apply(&raw_input))
+}
+
+fn resolver(source: &InputSource) -> Option<InputResolver> {
+	match source {
+		InputSource::Default => Some(InputResolver::new()),
+		InputSource::Dir(dir) => Some(InputResolver::new().with_input_dir(dir)),
+		InputSource::File(_) | InputSource::Stdin => None,
+	}
+}
+
+// every file the input for a day could be read from, most preferred first, and nothing for stdin
+pub fn input_candidates(year: u16, day: u8, source: &InputSource) -> Vec<PathBuf> {
+	match source {
+		InputSource::File(path) => vec![path.clone()],
+		_ => resolver(source).map(|resolver| resolver.candidates(year, day)).unwrap_or_default(),
+	}
+}
+
+// the answers file belonging to a set of inputs, None when reading a single file or stdin
+pub fn answers_file(year: u16, source: &InputSource) -> Option<PathBuf> {
+	resolver(source).map(|resolver| resolver.answers_file(year))
+}
+
+pub fn baseline_file(year: u16, source: &InputSource) -> Option<PathBuf> {
+	resolver(source).map(|resolver| resolver.baseline_file(year))
+}
+
+fn read_raw_input(year: u16, day: u8, source: &InputSource) -> Result<String> {
+	match source {
+		InputSource::Default => InputResolver::new().read(year, day),
+		InputSource::Dir(dir) => InputResolver::new().with_input_dir(dir).read(year, day),
+		InputSource::File(path) => input::read_file(path.clone()),
+		InputSource::Stdin => {
+			let mut input = String::new();
+			io::stdin().read_to_string(&mut input)?;
+			Ok(input)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	#[test]
+	fn test_registry_order() {
+		let years = YEARS.iter().map(|year| year.year).collect::<Vec<_>>();
+		assert!(years.is_sorted_by(|a, b| a < b), "years out of order: {:?}", years);
+
+		for Year { year, days } in YEARS {
+			assert!(days.iter().all(|solution| solution.year() == *year), "{} lists a day from another year", year);
+			// days can be skipped, since they needn't be solved in order
+			let days = days.iter().map(|solution| solution.day()).collect::<Vec<_>>();
+			assert!(days.is_sorted_by(|a, b| a < b) && days.iter().all(|day| (1..=25).contains(day)), "{} lists days out of order: {:?}", year, days);
+		}
+	}
+
+	#[test]
+	fn test_unknown_day() {
+		assert!(matches!(get_day(1999, 1), Err(Error::UnknownYear(1999))));
+		assert!(matches!(get_day(2025, 26), Err(Error::UnknownDay { year: 2025, day: 26 })));
+	}
+
+	#[test]
+	fn test_parse_once() -> Result<()> {
+		let solution = get_day(2025, 1)?;
+		let parsed = solution.parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82")?;
+		assert_eq!(1, parsed.day());
+		assert_eq!(3, solution.solve_part1(&parsed)?);
+		assert_eq!(6, solution.solve_part2(&parsed)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_run_concurrent() -> Result<()> {
+		let solution = get_day(2025, 11)?;
+		let input = "svr: fft\nfft: dac\ndac: out";
+		let (serial, concurrent) = (solution.run(input)?, solution.run_concurrent(input)?);
+		assert_eq!(serial.part1.answer.unwrap_err().to_string(), concurrent.part1.answer.unwrap_err().to_string());
+		assert_eq!(serial.part2.answer?, concurrent.part2.answer?);
+		assert_eq!(serial.part2.allocs, concurrent.part2.allocs);
+		Ok(())
+	}
+
+	#[test]
+	fn test_run_shared() -> Result<()> {
+		// day 8 sorts its edges once for both parts, and that's timed on its own
+		let solution = get_day(2025, 8)?;
+		let input = std::fs::read_to_string(examples_dir().join("2025/08/example.txt"))?;
+		for run in [solution.run(&input)?, solution.run_concurrent(&input)?] {
+			assert!(!run.shared.is_zero());
+			assert!(run.shared_allocs.allocations > 0);
+			assert_eq!(run.shared + run.part1.elapsed + run.part2.elapsed, run.solve());
+			assert_eq!(25272, run.part2.answer?);
+		}
+
+		// days that don't share work have nothing to report there
+		let run = get_day(2025, 1)?.run("L68\nL30")?;
+		assert!(run.shared.is_zero());
+		Ok(())
+	}
+
+	#[test]
+	fn test_malformed_input() {
+		// a day that's only been scaffolded takes any input, but doesn't answer it yet
+		for solution in YEARS.iter().flat_map(|year| year.days) {
+			let res = [solution.part1("not a puzzle input\n"), solution.part2("not a puzzle input\n")];
+			assert!(
+				res.iter().all(|res| matches!(res, Err(Error::Parse(_)))) || res.iter().all(|res| matches!(res, Err(Error::NoSolution(_)))),
+				"day {} of {} returned {:?}", solution.day(), solution.year(), res
+			);
+		}
+
+		// inputs that get past the parser, or nearly do, but aren't puzzles any solver can finish
+		let cases: &[(u8, &str, bool)] = &[
+			(1, "L9223372036854775808", true),
+			(1, "R18446744073709551615", true),
+			(2, "30-10", false),
+			(2, "12-18446744073709551615", false),
+			(3, "12345", false),
+			(3, "1", false),
+			(4, "..@\n@.", true),
+			(5, "5-3\n\n4", true),
+			(6, "1 2\n3\n+ *", true),
+			(7, "S.\n^.", false),
+			(7, ".S\n.^", false),
+			(7, "S.\n..\n", false),
+			(7, "S.\n.S", true),
+			(6, "4294967296 4294967296\n4294967296 4294967296\n*          *         ", false),
+			(7, "S..\n.^", true),
+			(8, "4294967296,0,0\n4294967296,1,0", false),
+			(9, "5,5", false),
+			(9, "99999999999,1\n1,99999999999", false),
+			(11, "you: out\nyou: out", true),
+			(11, "out: you\nyou: out", true),
+			(11, "you: aaa\naaa: you out", false),
+		];
+		for &(day, input, malformed) in cases {
+			let solution = get_day(2025, day).unwrap();
+			for res in [solution.part1(input), solution.part2(input)] {
+				match res {
+					Err(Error::Parse(_)) => assert!(malformed, "day {} rejected {:?}: {:?}", day, input, res),
+					_ => assert!(!malformed, "day {} accepted {:?}: {:?}", day, input, res),
+				}
+			}
+		}
+	}
 }
\ No newline at end of file
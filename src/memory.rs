@@ -0,0 +1,108 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// counts are per thread, so work on other threads never leaks into a measurement
+thread_local! {
+	static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+	static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+	// signed, since memory allocated on one thread can be freed on another
+	static LIVE_BYTES: Cell<i64> = const { Cell::new(0) };
+	static PEAK_LIVE_BYTES: Cell<i64> = const { Cell::new(0) };
+}
+
+fn record_live(change: i64) {
+	let _ = LIVE_BYTES.try_with(|live| {
+		live.set(live.get() + change);
+		let _ = PEAK_LIVE_BYTES.try_with(|peak| peak.set(peak.get().max(live.get())));
+	});
+}
+
+fn record_allocation(size: usize) {
+	// try_with, since allocations can still happen while thread locals are being torn down
+	let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+	let _ = ALLOCATED_BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+	record_live(size as i64);
+}
+
+// the system allocator, counting every allocation made through it
+// install it with #[global_allocator] in a binary to get numbers out of count_allocations
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		record_allocation(layout.size());
+		unsafe { System.alloc(layout) }
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		record_allocation(layout.size());
+		unsafe { System.alloc_zeroed(layout) }
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		record_live(-(layout.size() as i64));
+		unsafe { System.dealloc(ptr, layout) }
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		record_allocation(new_size);
+		record_live(-(layout.size() as i64));
+		unsafe { System.realloc(ptr, layout, new_size) }
+	}
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct AllocStats {
+	pub allocations: u64,
+	pub bytes: u64,
+	// the most bytes live at once, over what was already live when counting started
+	pub peak_bytes: u64,
+}
+
+// allocations made on this thread while running f, all zero unless CountingAllocator is installed
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+	let allocations = ALLOCATIONS.with(Cell::get);
+	let bytes = ALLOCATED_BYTES.with(Cell::get);
+	let live = LIVE_BYTES.with(Cell::get);
+	// the peak restarts from what's live now, and is put back afterwards so an outer count still sees f's peak
+	let outer_peak = PEAK_LIVE_BYTES.replace(live);
+	let res = f();
+	let peak = PEAK_LIVE_BYTES.with(Cell::get);
+	PEAK_LIVE_BYTES.set(outer_peak.max(peak));
+
+	(res, AllocStats {
+		allocations: ALLOCATIONS.with(Cell::get) - allocations,
+		bytes: ALLOCATED_BYTES.with(Cell::get) - bytes,
+		peak_bytes: (peak - live).max(0) as u64,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::memory::*;
+
+	#[global_allocator]
+	static ALLOC: CountingAllocator = CountingAllocator;
+
+	#[test]
+	fn test_count_allocations() {
+		let (numbers, stats) = count_allocations(|| vec![0u64; 16]);
+		assert_eq!(AllocStats { allocations: 1, bytes: 128, peak_bytes: 128 }, stats);
+
+		let (_, stats) = count_allocations(|| numbers.iter().sum::<u64>());
+		assert_eq!(AllocStats::default(), stats);
+	}
+
+	#[test]
+	fn test_peak_bytes() {
+		// freed memory counts towards the bytes allocated, but only what was live at once counts towards the peak
+		let ((_, inner), outer) = count_allocations(|| {
+			for _ in 0..4 {
+				drop(vec![0u8; 1000]);
+			}
+			count_allocations(|| drop(vec![0u8; 3000]))
+		});
+		assert_eq!(AllocStats { allocations: 1, bytes: 3000, peak_bytes: 3000 }, inner);
+		assert_eq!(AllocStats { allocations: 5, bytes: 7000, peak_bytes: 3000 }, outer);
+	}
+}
\ No newline at end of file
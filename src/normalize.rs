@@ -0,0 +1,78 @@
+use itertools::Itertools;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Normalization {
+	pub strip_bom: bool,
+	pub unify_line_endings: bool,
+	pub trim_trailing_whitespace: bool,
+	// also drops the final newline, so parsers never have to expect one
+	pub trim_trailing_blank_lines: bool,
+}
+
+impl Normalization {
+	pub const DEFAULT: Self = Self {
+		strip_bom: true,
+		unify_line_endings: true,
+		trim_trailing_whitespace: true,
+		trim_trailing_blank_lines: true,
+	};
+
+	pub const NONE: Self = Self {
+		strip_bom: false,
+		unify_line_endings: false,
+		trim_trailing_whitespace: false,
+		trim_trailing_blank_lines: false,
+	};
+
+	pub fn apply(&self, input: &str) -> String {
+		let input = if self.strip_bom {
+			input.strip_prefix('\u{feff}').unwrap_or(input)
+		} else {
+			input
+		};
+
+		let mut res = if self.unify_line_endings {
+			input.replace("\r\n", "\n")
+		} else {
+			String::from(input)
+		};
+
+		if self.trim_trailing_whitespace {
+			res = res.split('\n').map(|line| line.trim_end_matches([' ', '\t'])).join("\n");
+		}
+
+		if self.trim_trailing_blank_lines {
+			let trimmed_len = res.trim_end_matches(['\n', '\r']).len();
+			res.truncate(trimmed_len);
+		}
+
+		res
+	}
+}
+
+impl Default for Normalization {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::normalize::*;
+
+	#[test]
+	fn test_default() {
+		assert_eq!("L68\nL30\nR48", Normalization::DEFAULT.apply("\u{feff}L68 \r\nL30\r\nR48\r\n\r\n"));
+	}
+
+	#[test]
+	fn test_keep_trailing_whitespace() {
+		let normalization = Normalization { trim_trailing_whitespace: false, ..Normalization::DEFAULT };
+		assert_eq!("64 \n+  ", normalization.apply("64 \r\n+  \r\n"));
+	}
+
+	#[test]
+	fn test_none() {
+		assert_eq!("\u{feff}a\r\n", Normalization::NONE.apply("\u{feff}a\r\n"));
+	}
+}
\ No newline at end of file
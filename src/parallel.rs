@@ -0,0 +1,53 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use crate::cancel::propagate;
+
+// as many workers as the machine has cores, or one when that can't be told
+pub fn default_jobs() -> usize {
+	thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+// f applied to every item on up to jobs threads at once, with the results in the same order as the items
+// workers take the next item as they finish one, so a single slow item doesn't hold up the ones queued behind it
+pub fn parallel_map<I: Sync, T: Send>(items: &[I], jobs: usize, f: impl Fn(&I) -> T + Sync) -> Vec<T> {
+	let next = AtomicUsize::new(0);
+	let results = Mutex::new(Vec::with_capacity(items.len()));
+	thread::scope(|scope| {
+		for _ in 0..jobs.clamp(1, items.len().max(1)) {
+			scope.spawn(propagate(|| {
+				loop {
+					let ix = next.fetch_add(1, Ordering::Relaxed);
+					let Some(item) = items.get(ix) else { break };
+					let res = f(item);
+					results.lock().unwrap().push((ix, res));
+				}
+			}));
+		}
+	});
+
+	let mut results = results.into_inner().unwrap();
+	results.sort_unstable_by_key(|&(ix, _)| ix);
+	results.into_iter().map(|(_, res)| res).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+	use crate::parallel::*;
+
+	#[test]
+	fn test_parallel_map() {
+		// the early items take longest, so they finish last but still come back first
+		let items = (0..20u64).collect::<Vec<_>>();
+		let squares = parallel_map(&items, 4, |&item| {
+			thread::sleep(Duration::from_millis(20u64.saturating_sub(item)));
+			item * item
+		});
+		assert_eq!(items.iter().map(|item| item * item).collect::<Vec<_>>(), squares);
+
+		assert_eq!(vec![2], parallel_map(&[1], 0, |item| item * 2));
+		assert!(parallel_map(&[] as &[u8], 4, |item| *item).is_empty());
+	}
+}
\ No newline at end of file
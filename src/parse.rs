@@ -0,0 +1,238 @@
+use std::cell::RefCell;
+use std::fmt;
+use grid::Grid;
+use nom::combinator::all_consuming;
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::Parser;
+use crate::{Error, Result};
+
+pub type IResult<I, O> = nom::IResult<I, O, ParseError<I>>;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expected {
+	Char(char),
+	Kind(ErrorKind),
+	Context(&'static str),
+}
+impl fmt::Display for Expected {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Expected::Char(chr) => write!(f, "{:?}", chr),
+			Expected::Kind(ErrorKind::Digit) => write!(f, "a number"),
+			Expected::Kind(ErrorKind::Alpha) => write!(f, "a letter"),
+			Expected::Kind(ErrorKind::CrLf) => write!(f, "a line ending"),
+			Expected::Kind(ErrorKind::Eof) => write!(f, "end of input"),
+			Expected::Kind(ErrorKind::Space | ErrorKind::MultiSpace) => write!(f, "whitespace"),
+			Expected::Kind(ErrorKind::Satisfy) => write!(f, "a matching character"),
+			Expected::Kind(ErrorKind::MapRes | ErrorKind::MapOpt) => write!(f, "a value in range"),
+			Expected::Kind(kind) => write!(f, "{}", kind.description().to_lowercase()),
+			Expected::Context(context) => write!(f, "{}", context),
+		}
+	}
+}
+
+// the failure that got furthest into the input, recorded while parsing
+// combinators like many1 swallow inner errors once they have matched at least once,
+// so without this every mistake after the first line would surface as "expected end of input"
+thread_local! {
+	static FURTHEST_FAILURE: RefCell<Option<(usize, Vec<Expected>)>> = const { RefCell::new(None) };
+}
+
+fn record_failure(input: &str, expected: &Expected) {
+	FURTHEST_FAILURE.with_borrow_mut(|furthest| match furthest {
+		Some((remaining, expectations)) if *remaining == input.len() => {
+			if !expectations.contains(expected) {
+				expectations.push(expected.clone());
+			}
+		},
+		Some((remaining, _)) if *remaining < input.len() => {},
+		_ => *furthest = Some((input.len(), vec![expected.clone()])),
+	});
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError<I> {
+	pub input: I,
+	pub expected: Vec<Expected>,
+}
+impl<'a> ParseError<&'a str> {
+	fn new(input: &'a str, expected: Expected) -> Self {
+		record_failure(input, &expected);
+		Self { input, expected: vec![expected] }
+	}
+}
+impl<'a> nom::error::ParseError<&'a str> for ParseError<&'a str> {
+	fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+		Self::new(input, Expected::Kind(kind))
+	}
+
+	fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+		other
+	}
+
+	fn from_char(input: &'a str, chr: char) -> Self {
+		Self::new(input, Expected::Char(chr))
+	}
+
+	fn or(mut self, other: Self) -> Self {
+		if self.input.len() == other.input.len() {
+			self.expected.extend(other.expected);
+			self.expected.dedup();
+			self
+		} else if self.input.len() < other.input.len() {
+			self
+		} else {
+			other
+		}
+	}
+}
+impl<'a> ContextError<&'a str> for ParseError<&'a str> {
+	fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+		// failures further in are more specific than the context wrapped around them
+		if other.input.len() != input.len() {
+			return other;
+		}
+		FURTHEST_FAILURE.with_borrow_mut(|furthest| {
+			if let Some((remaining, expectations)) = furthest && *remaining == input.len() {
+				expectations.retain(|expected| !other.expected.contains(expected));
+			}
+		});
+		Self::new(input, Expected::Context(context))
+	}
+}
+impl<'a, E> FromExternalError<&'a str, E> for ParseError<&'a str> {
+	fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+		Self::new(input, Expected::Kind(kind))
+	}
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SourceLocation {
+	pub line: usize,
+	pub column: usize,
+	pub source_line: String,
+}
+impl SourceLocation {
+	fn locate(input: &str, remaining: &str) -> Self {
+		let offset = input.len() - remaining.len();
+		let line_start = input[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+		let line_end = input[offset..].find('\n').map_or(input.len(), |ix| offset + ix);
+
+		Self {
+			line: input[..offset].matches('\n').count() + 1,
+			column: input[line_start..offset].chars().count() + 1,
+			source_line: input[line_start..line_end].trim_end_matches('\r').to_string(),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseDiagnostic {
+	pub location: Option<SourceLocation>,
+	pub expected: Vec<String>,
+}
+impl ParseDiagnostic {
+	pub fn expected(expected: impl Into<String>) -> Self {
+		Self { location: None, expected: vec![expected.into()] }
+	}
+
+	// for problems only noticed once the input has parsed, at a zero-based row and column of it
+	pub fn at(input: &str, row: usize, column: usize, expected: impl Into<String>) -> Self {
+		let source_line = input.lines().nth(row).unwrap_or_default().trim_end_matches('\r').to_string();
+		Self { location: Some(SourceLocation { line: row + 1, column: column + 1, source_line }), expected: vec![expected.into()] }
+	}
+
+	fn from_failure(input: &str, remaining_len: usize, mut expected: Vec<Expected>) -> Self {
+		// "end of input" is only interesting if nothing else could have matched here
+		if expected.len() > 1 {
+			expected.retain(|expected| *expected != Expected::Kind(ErrorKind::Eof));
+		}
+		let remaining = &input[(input.len() - remaining_len)..];
+
+		Self {
+			location: Some(SourceLocation::locate(input, remaining)),
+			expected: expected.iter().map(|expected| expected.to_string()).collect(),
+		}
+	}
+}
+impl fmt::Display for ParseDiagnostic {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "expected ")?;
+		for (ix, expected) in self.expected.iter().enumerate() {
+			match ix {
+				0 => write!(f, "{}", expected)?,
+				_ if ix == self.expected.len() - 1 => write!(f, " or {}", expected)?,
+				_ => write!(f, ", {}", expected)?,
+			}
+		}
+		if let Some(SourceLocation { line, column, source_line }) = &self.location {
+			write!(f, " at line {}, column {}", line, column)?;
+			let gutter = " ".repeat(line.to_string().len());
+			write!(f, "\n{} |\n{} | {}\n{} | {}^", gutter, line, source_line, gutter, " ".repeat(column - 1))?;
+		}
+		Ok(())
+	}
+}
+
+// runs a parser over the whole input, turning any failure into a diagnostic that points at the input
+pub fn parse_complete<'a, O>(input: &'a str, parser: impl Parser<&'a str, Output = O, Error = ParseError<&'a str>>) -> Result<O> {
+	FURTHEST_FAILURE.with_borrow_mut(|furthest| *furthest = None);
+
+	let res = all_consuming(parser).parse(input);
+	let furthest = FURTHEST_FAILURE.with_borrow_mut(|furthest| furthest.take());
+
+	match res {
+		Ok((_, output)) => Ok(output),
+		Err(nom::Err::Incomplete(_)) => Err(Error::Parse(ParseDiagnostic::expected("more input"))),
+		Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+			let (remaining_len, expected) = furthest.unwrap_or((err.input.len(), err.expected));
+			Err(Error::Parse(ParseDiagnostic::from_failure(input, remaining_len, expected)))
+		},
+	}
+}
+
+// rows read off consecutive lines as a grid, failing on the first row not as wide as the first rather than letting Grid::from panic
+pub fn rectangular<T: Clone>(input: &str, rows: Vec<Vec<T>>, unit: &str) -> Result<Grid<T>> {
+	if let Some(width) = rows.first().map(Vec::len) && let Some(row_ix) = rows.iter().position(|row| row.len() != width) {
+		return Err(Error::Parse(ParseDiagnostic::at(input, row_ix, 0, format!("{} {} like the first row", width, unit))));
+	}
+	Ok(Grid::from(rows))
+}
+
+#[cfg(test)]
+mod tests {
+	use nom::branch::alt;
+	use nom::character::complete::{char, digit1, line_ending};
+	use nom::multi::separated_list1;
+	use nom::sequence::preceded;
+	use crate::parse::*;
+
+	fn parse_turns(input: &str) -> Result<Vec<&str>> {
+		parse_complete(input, separated_list1(
+			line_ending,
+			alt((preceded(char('L'), digit1), preceded(char('R'), digit1)))
+		))
+	}
+
+	#[test]
+	fn test_diagnostic_location() {
+		let Err(Error::Parse(diagnostic)) = parse_turns("L68\nR30\nX5") else { panic!("expected a parse error") };
+		assert_eq!(Some(SourceLocation { line: 3, column: 1, source_line: String::from("X5") }), diagnostic.location);
+		assert_eq!("expected 'L' or 'R' at line 3, column 1\n  |\n3 | X5\n  | ^", diagnostic.to_string());
+	}
+
+	#[test]
+	fn test_rectangular() {
+		let rows = |input: &str| input.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+		assert_eq!((2, 3), rectangular("..@\n@..", rows("..@\n@.."), "cells").unwrap().size());
+		let Err(Error::Parse(diagnostic)) = rectangular("..@\n@.\n...", rows("..@\n@.\n..."), "cells") else { panic!("expected a parse error") };
+		assert_eq!("expected 3 cells like the first row at line 2, column 1\n  |\n2 | @.\n  | ^", diagnostic.to_string());
+	}
+
+	#[test]
+	fn test_diagnostic_mid_line() {
+		let Err(Error::Parse(diagnostic)) = parse_turns("L68\nRx\n") else { panic!("expected a parse error") };
+		assert_eq!(Some(SourceLocation { line: 2, column: 2, source_line: String::from("Rx") }), diagnostic.location);
+		assert_eq!(vec![String::from("a number")], diagnostic.expected);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+use std::fmt::Write;
+use std::ops::Range;
+use itertools::Itertools;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Color(pub u8, pub u8, pub u8);
+impl Color {
+	pub const BLACK: Color = Color(0, 0, 0);
+	pub const WHITE: Color = Color(255, 255, 255);
+
+	// from one colour to another, with amount from 0 to 1
+	pub fn blend(self, other: Color, amount: f64) -> Color {
+		let amount = amount.clamp(0.0, 1.0);
+		let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * amount).round() as u8;
+		Color(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+	}
+
+	fn hex(self) -> String {
+		format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+	}
+}
+
+// coordinates are whole tiles, x to the right and y down, so a grid cell at (x, y) covers x..x+1 and y..y+1
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Shape {
+	Cell { x: u64, y: u64, glyph: char, color: Color },
+	// every tile from one corner to the other, inclusive
+	Rect { from: (u64, u64), to: (u64, u64), glyph: char, color: Color },
+	// the outline through the centres of the given tiles, closed back to the first
+	Polygon { points: Vec<(u64, u64)>, glyph: char, color: Color },
+}
+
+// something to draw, independent of which backend draws it
+// shapes pushed later are drawn over the ones before them
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Scene {
+	pub width: u64,
+	pub height: u64,
+	pub background: Color,
+	pub shapes: Vec<Shape>,
+}
+
+pub trait Render {
+	fn render(&self) -> Scene;
+}
+
+// the longest side of the text and image backends, anything bigger is scaled down to fit
+const MAX_COLUMNS: u64 = 160;
+const MAX_PIXELS: u64 = 1024;
+
+// tiles drawn on a raster, each either empty or holding the glyph and colour of the last shape on it
+struct Raster {
+	columns: usize,
+	rows: usize,
+	// raster cells per tile, below 1 when the scene is shrunk to fit
+	scale: f64,
+	cells: Vec<Option<(char, Color)>>,
+}
+
+impl Raster {
+	fn new(scene: &Scene, max_side: u64, grow: bool) -> Self {
+		let longest = scene.width.max(scene.height).max(1);
+		// whole cells per tile when growing, so grids stay crisp
+		let scale = if longest > max_side {
+			max_side as f64 / longest as f64
+		} else if grow {
+			(max_side / longest) as f64
+		} else {
+			1.0
+		};
+		let columns = ((scene.width as f64 * scale).ceil() as usize).max(1);
+		let rows = ((scene.height as f64 * scale).ceil() as usize).max(1);
+		Self { columns, rows, scale, cells: vec![None; columns * rows] }
+	}
+
+	// the raster cells covering tiles from..=to along one axis, always at least one
+	fn span(&self, from: u64, to: u64, len: usize) -> Range<usize> {
+		let start = ((from as f64 * self.scale) as usize).min(len - 1);
+		let end = (((to + 1) as f64 * self.scale).ceil() as usize).clamp(start + 1, len);
+		start..end
+	}
+
+	fn fill(&mut self, from: (u64, u64), to: (u64, u64), glyph: char, color: Color) {
+		for row in self.span(from.1, to.1, self.rows) {
+			for col in self.span(from.0, to.0, self.columns) {
+				self.cells[row * self.columns + col] = Some((glyph, color));
+			}
+		}
+	}
+
+	fn centre(&self, (x, y): (u64, u64)) -> (f64, f64) {
+		let centre = |tile: u64, len: usize| ((tile as f64 + 0.5) * self.scale).min(len as f64 - 0.5);
+		(centre(x, self.columns), centre(y, self.rows))
+	}
+
+	fn line(&mut self, from: (u64, u64), to: (u64, u64), glyph: char, color: Color) {
+		let (from_col, from_row) = self.centre(from);
+		let (to_col, to_row) = self.centre(to);
+		let steps = (to_col - from_col).abs().max((to_row - from_row).abs()).ceil().max(1.0) as usize;
+		for step in 0..=steps {
+			let along = step as f64 / steps as f64;
+			let col = (from_col + (to_col - from_col) * along) as usize;
+			let row = (from_row + (to_row - from_row) * along) as usize;
+			self.cells[row * self.columns + col] = Some((glyph, color));
+		}
+	}
+
+	fn draw(scene: &Scene, max_side: u64, grow: bool) -> Self {
+		let mut raster = Raster::new(scene, max_side, grow);
+		for shape in &scene.shapes {
+			match shape {
+				&Shape::Cell { x, y, glyph, color } => raster.fill((x, y), (x, y), glyph, color),
+				&Shape::Rect { from, to, glyph, color } => {
+					raster.fill((from.0.min(to.0), from.1.min(to.1)), (from.0.max(to.0), from.1.max(to.1)), glyph, color);
+				},
+				Shape::Polygon { points, glyph, color } => {
+					for (&from, &to) in points.iter().circular_tuple_windows() {
+						raster.line(from, to, *glyph, *color);
+					}
+				},
+			}
+		}
+		raster
+	}
+}
+
+impl Scene {
+	pub fn new(width: u64, height: u64) -> Self {
+		Self { width, height, background: Color::WHITE, shapes: vec![] }
+	}
+
+	pub fn push(&mut self, shape: Shape) {
+		self.shapes.push(shape);
+	}
+
+	pub fn to_ascii(&self) -> String {
+		// a character per tile, unless that would be too wide for a terminal
+		let raster = Raster::draw(self, MAX_COLUMNS, false);
+		raster.cells.chunks(raster.columns)
+			.map(|row| row.iter().map(|cell| cell.map_or(' ', |(glyph, _)| glyph)).collect::<String>())
+			.join("\n")
+	}
+
+	// a binary P6 image, which most image viewers open directly
+	pub fn to_ppm(&self) -> Vec<u8> {
+		let raster = Raster::draw(self, MAX_PIXELS, true);
+		let mut res = format!("P6\n{} {}\n255\n", raster.columns, raster.rows).into_bytes();
+		for cell in &raster.cells {
+			let Color(r, g, b) = cell.map_or(self.background, |(_, color)| color);
+			res.extend([r, g, b]);
+		}
+		res
+	}
+
+	// one unit per tile, left for the viewer to scale
+	pub fn to_svg(&self) -> String {
+		let mut res = format!(
+			"<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+			self.width, self.height
+		);
+		let _ = writeln!(res, "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>", self.width, self.height, self.background.hex());
+		for shape in &self.shapes {
+			let _ = match shape {
+				Shape::Cell { x, y, color, .. } => {
+					writeln!(res, "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"{}\"/>", x, y, color.hex())
+				},
+				Shape::Rect { from, to, color, .. } => writeln!(
+					res,
+					"<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\" fill-opacity=\"0.5\"/>",
+					from.0.min(to.0), from.1.min(to.1), from.0.abs_diff(to.0) + 1, from.1.abs_diff(to.1) + 1, color.hex()
+				),
+				Shape::Polygon { points, color, .. } => writeln!(
+					res,
+					"<polygon points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"1\" vector-effect=\"non-scaling-stroke\"/>",
+					points.iter().map(|(x, y)| format!("{}.5,{}.5", x, y)).join(" "), color.hex()
+				),
+			};
+		}
+		res.push_str("</svg>");
+		res
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::render::*;
+
+	fn scene() -> Scene {
+		let mut scene = Scene::new(4, 3);
+		scene.push(Shape::Rect { from: (0, 0), to: (3, 2), glyph: '.', color: Color::WHITE });
+		scene.push(Shape::Cell { x: 1, y: 1, glyph: '@', color: Color(255, 0, 0) });
+		scene
+	}
+
+	#[test]
+	fn test_ascii() {
+		assert_eq!("....\n.@..\n....", scene().to_ascii());
+
+		let mut outline = Scene::new(1000, 1000);
+		outline.push(Shape::Polygon { points: vec![(0, 0), (999, 0), (999, 999), (0, 999)], glyph: '#', color: Color::BLACK });
+		let ascii = outline.to_ascii();
+		assert_eq!("#".repeat(160), ascii.lines().next().unwrap());
+		assert_eq!(format!("#{}#", " ".repeat(158)), ascii.lines().nth(80).unwrap());
+	}
+
+	#[test]
+	fn test_ppm() {
+		// a small scene is drawn at whole multiples of its size, so every tile is the same number of pixels
+		let ppm = scene().to_ppm();
+		let header = b"P6\n1024 768\n255\n";
+		assert!(ppm.starts_with(header));
+		assert_eq!(header.len() + 1024 * 768 * 3, ppm.len());
+
+		// the pixel in the middle of the red tile
+		let offset = header.len() + (384 * 1024 + 384) * 3;
+		assert_eq!([255, 0, 0], ppm[offset..(offset + 3)]);
+	}
+
+	#[test]
+	fn test_svg() {
+		let svg = scene().to_svg();
+		assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 4 3\""));
+		assert!(svg.contains("<rect x=\"1\" y=\"1\" width=\"1\" height=\"1\" fill=\"#ff0000\"/>"));
+		assert!(svg.ends_with("</svg>"));
+	}
+
+	#[test]
+	fn test_blend() {
+		assert_eq!(Color(128, 128, 128), Color::BLACK.blend(Color::WHITE, 0.5));
+		assert_eq!(Color::WHITE, Color::BLACK.blend(Color::WHITE, 2.0));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::time::Duration;
+use crate::{AllocStats, Day, DayRun, PartRun, Result};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Stage {
+	Parse,
+	// work a day does once for both parts, only reported for days that have any
+	Shared,
+	Part1,
+	Part2,
+}
+impl fmt::Display for Stage {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Stage::Parse => write!(f, "parse"),
+			Stage::Shared => write!(f, "shared"),
+			Stage::Part1 => write!(f, "part1"),
+			Stage::Part2 => write!(f, "part2"),
+		}
+	}
+}
+
+// one row of machine readable output, a day's parse or one of its parts
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Record {
+	pub year: u16,
+	pub day: u8,
+	pub title: &'static str,
+	pub stage: Stage,
+	pub answer: Option<String>,
+	pub error: Option<String>,
+	pub elapsed: Duration,
+	pub allocs: AllocStats,
+}
+
+// a failure to read or parse the input is reported against the parse stage
+pub fn records(solution: &dyn Day, run: Result<DayRun>) -> Vec<Record> {
+	let record = |stage, answer, error, elapsed, allocs| Record {
+		year: solution.year(),
+		day: solution.day(),
+		title: solution.title(),
+		stage,
+		answer,
+		error,
+		elapsed,
+		allocs,
+	};
+
+	match run {
+		Ok(DayRun { parse, parse_allocs, shared, shared_allocs, part1, part2 }) => {
+			let mut records = vec![record(Stage::Parse, None, None, parse, parse_allocs)];
+			if !shared.is_zero() {
+				records.push(record(Stage::Shared, None, None, shared, shared_allocs));
+			}
+			for (stage, PartRun { answer, elapsed, allocs }) in [(Stage::Part1, part1), (Stage::Part2, part2)] {
+				let (answer, error) = match answer {
+					Ok(answer) => (Some(answer.to_string()), None),
+					Err(err) => (None, Some(err.to_string())),
+				};
+				records.push(record(stage, answer, error, elapsed, allocs));
+			}
+			records
+		},
+		Err(err) => vec![record(Stage::Parse, None, Some(err.to_string()), Duration::ZERO, AllocStats::default())],
+	}
+}
+
+fn json_string(text: &str) -> String {
+	let mut res = String::from("\"");
+	for chr in text.chars() {
+		match chr {
+			'"' => res.push_str("\\\""),
+			'\\' => res.push_str("\\\\"),
+			'\n' => res.push_str("\\n"),
+			'\r' => res.push_str("\\r"),
+			'\t' => res.push_str("\\t"),
+			chr if chr.is_control() => res.push_str(&format!("\\u{:04x}", chr as u32)),
+			chr => res.push(chr),
+		}
+	}
+	res.push('"');
+	res
+}
+
+fn json_option(text: &Option<String>) -> String {
+	text.as_deref().map_or(String::from("null"), json_string)
+}
+
+// answers are always strings, since big answers don't survive being read back as JSON numbers
+pub fn to_json(records: &[Record]) -> String {
+	let rows = records.iter().map(|record| format!(
+		"  {{\"year\": {}, \"day\": {}, \"title\": {}, \"stage\": \"{}\", \"answer\": {}, \"error\": {}, \"nanos\": {}, \"allocations\": {}, \"bytes\": {}, \"peak_bytes\": {}}}",
+		record.year, record.day, json_string(record.title), record.stage, json_option(&record.answer), json_option(&record.error),
+		record.elapsed.as_nanos(), record.allocs.allocations, record.allocs.bytes, record.allocs.peak_bytes
+	)).collect::<Vec<_>>();
+
+	if rows.is_empty() {
+		String::from("[]")
+	} else {
+		format!("[\n{}\n]", rows.join(",\n"))
+	}
+}
+
+fn csv_field(text: &str) -> String {
+	if text.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", text.replace('"', "\"\""))
+	} else {
+		String::from(text)
+	}
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+	let mut res = String::from("year,day,title,stage,answer,error,nanos,allocations,bytes,peak_bytes");
+	for record in records {
+		res.push_str(&format!(
+			"\n{},{},{},{},{},{},{},{},{},{}",
+			record.year, record.day, csv_field(record.title), record.stage,
+			csv_field(record.answer.as_deref().unwrap_or_default()), csv_field(record.error.as_deref().unwrap_or_default()),
+			record.elapsed.as_nanos(), record.allocs.allocations, record.allocs.bytes, record.allocs.peak_bytes
+		));
+	}
+	res
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::report::*;
+	use crate::{get_day, Error};
+
+	#[test]
+	fn test_records() -> Result<()> {
+		let solution = get_day(2025, 11)?;
+		let records = records(solution, solution.run("svr: fft\nfft: dac\ndac: out"));
+		// building the graph both parts walk is reported on its own
+		assert_eq!(vec![Stage::Parse, Stage::Shared, Stage::Part1, Stage::Part2], records.iter().map(|record| record.stage).collect::<Vec<_>>());
+		assert_eq!(Some(String::from("no solution: no device labelled 'you'")), records[2].error);
+		assert_eq!(Some(String::from("1")), records[3].answer);
+
+		let json = to_json(&records);
+		assert!(json.contains("\"stage\": \"part1\", \"answer\": null, \"error\": \"no solution: no device labelled 'you'\""));
+		let csv = to_csv(&records);
+		assert!(csv.starts_with("year,day,title,stage,answer,error,nanos,allocations,bytes,peak_bytes\n2025,11,Reactor,parse,,,"));
+		assert!(csv.contains("\n2025,11,Reactor,shared,,,"));
+		assert!(csv.contains("\n2025,11,Reactor,part2,1,,"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_escaping() {
+		let failed = records(get_day(2025, 1).unwrap(), Err(Error::no_solution("a \"quoted\",\nmultiline reason")));
+		assert!(to_json(&failed).contains("\"error\": \"no solution: a \\\"quoted\\\",\\nmultiline reason\""));
+		assert!(to_csv(&failed).ends_with(",parse,,\"no solution: a \"\"quoted\"\",\nmultiline reason\",0,0,0,0"));
+	}
+}
\ No newline at end of file
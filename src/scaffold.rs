@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::{Error, Result};
+
+fn read(path: &Path) -> Result<String> {
+	fs::read_to_string(path).map_err(|source| Error::Io { path: Some(path.to_path_buf()), source })
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent).map_err(|source| Error::Io { path: Some(parent.to_path_buf()), source })?;
+	}
+	fs::write(path, contents).map_err(|source| Error::Io { path: Some(path.to_path_buf()), source })
+}
+
+fn fill_template(template: &str, year: u16, day: u8, title: &str) -> String {
+	template
+		.replace("const YEAR: u16 = 0;", &format!("const YEAR: u16 = {};", year))
+		.replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+		.replace("yYYYY", &format!("y{}", year))
+		.replace("YYYY/NN", &format!("{}/{:0>2}", year, day))
+		.replace("\"TITLE\"", &format!("{:?}", title))
+		.replace("DayNN", &format!("Day{:0>2}", day))
+		.replace("dayNN", &format!("day{:0>2}", day))
+}
+
+// inserts line into the run of lines like "<prefix>NN...", keeping that run ordered by the number after the prefix
+fn insert_numbered_line(text: &str, prefix: &str, number: u16, digits: usize, line: &str) -> Result<String> {
+	let mut lines = text.split('\n').map(String::from).collect::<Vec<_>>();
+	let numbers = lines.iter().enumerate()
+		.filter_map(|(ix, line)| {
+			let rest = line.strip_prefix(prefix)?;
+			let existing = rest.get(..digits)?.parse::<u16>().ok()?;
+			Some((ix, existing))
+		})
+		.collect::<Vec<_>>();
+
+	if numbers.iter().any(|&(_, existing)| existing == number) {
+		return Err(Error::scaffold(format!("{:0>digits$} is already listed as '{}'", number, line.trim())));
+	}
+	let ix = match numbers.iter().rev().find(|&&(_, existing)| existing < number) {
+		Some(&(ix, _)) => ix + 1,
+		None => numbers.first().map(|&(ix, _)| ix)
+			.ok_or_else(|| Error::scaffold(format!("no lines starting with '{}' to add {:0>digits$} to", prefix.trim(), number)))?,
+	};
+	lines.insert(ix, String::from(line));
+	Ok(lines.join("\n"))
+}
+
+fn insert_day_line(text: &str, prefix: &str, day: u8, line: &str) -> Result<String> {
+	insert_numbered_line(text, prefix, day as u16, 2, line)
+}
+
+// the registry of a year that doesn't have any days yet
+fn year_module(day: u8) -> String {
+	format!("use crate::Day;\n\npub mod day{:0>2};\n\npub static DAYS: &[&dyn Day] = &[\n\t&day{:0>2}::Day{:0>2},\n];", day, day, day)
+}
+
+// creates the module and example files for a new day and registers it, returning every path touched
+// the first day of a year also creates that year's module and registers it in lib.rs
+pub fn scaffold_day(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+	let year_dir = root.join("src").join(format!("y{}", year));
+	let module = year_dir.join(format!("day{:0>2}.rs", day));
+	let registry = year_dir.join("mod.rs");
+	let examples = root.join("tests").join("examples").join(year.to_string()).join(format!("{:0>2}", day));
+	let lib = root.join("src").join("lib.rs");
+
+	if let Some(existing) = [&module, &examples].into_iter().find(|path| path.exists()) {
+		return Err(Error::scaffold(format!("{} already exists", existing.display())));
+	}
+
+	// work out every edit before writing anything, so a failure leaves the tree untouched
+	let module_source = fill_template(&read(&root.join("src").join("dayNN.rs"))?, year, day, title);
+	let mut edits = vec![];
+	if registry.exists() {
+		let registry_source = read(&registry)?;
+		let registry_source = insert_day_line(&registry_source, "pub mod day", day, &format!("pub mod day{:0>2};", day))?;
+		let registry_source = insert_day_line(&registry_source, "\t&day", day, &format!("\t&day{:0>2}::Day{:0>2},", day, day))?;
+		edits.push((registry, registry_source));
+	} else {
+		let lib_source = read(&lib)?;
+		let lib_source = insert_numbered_line(&lib_source, "pub mod y", year, 4, &format!("pub mod y{};", year))?;
+		let lib_source = insert_numbered_line(&lib_source, "\tYear { year: ", year, 4, &format!("\tYear {{ year: {}, days: y{}::DAYS }},", year, year))?;
+		edits.push((registry, year_module(day)));
+		edits.push((lib, lib_source));
+	}
+
+	write(&module, &module_source)?;
+	write(&examples.join("example.txt"), "")?;
+	write(&examples.join("example.answers"), &format!("# day part answer\n# {:0>2} 1 <answer>\n", day))?;
+	let mut paths = vec![module, examples.join("example.txt"), examples.join("example.answers")];
+	for (path, source) in edits {
+		write(&path, &source)?;
+		paths.push(path);
+	}
+
+	Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::env;
+	use crate::scaffold::*;
+
+	const REGISTRY: &str = "use crate::Day;
+
+pub mod day01;
+pub mod day03;
+
+pub static DAYS: &[&dyn Day] = &[
+	&day01::Day01,
+	&day03::Day03,
+];";
+
+	const LIB: &str = "pub mod y2025;
+
+pub static YEARS: &[Year] = &[
+	Year { year: 2025, days: y2025::DAYS },
+];";
+
+	#[test]
+	fn test_insert_day_line() -> Result<()> {
+		let registry = insert_day_line(REGISTRY, "pub mod day", 2, "pub mod day02;")?;
+		let registry = insert_day_line(&registry, "\t&day", 2, "\t&day02::Day02,")?;
+		assert_eq!(REGISTRY.replace("day01;\n", "day01;\npub mod day02;\n").replace("Day01,\n", "Day01,\n\t&day02::Day02,\n"), registry);
+
+		assert!(matches!(insert_day_line(REGISTRY, "pub mod day", 3, "pub mod day03;"), Err(Error::Scaffold(_))));
+		assert_eq!(year_module(1), REGISTRY.replace("pub mod day03;\n", "").replace("\t&day03::Day03,\n", ""));
+		Ok(())
+	}
+
+	#[test]
+	fn test_scaffold_day() -> Result<()> {
+		let root = env::temp_dir().join(format!("aoc25-scaffold-{}", std::process::id()));
+		let _ = fs::remove_dir_all(&root);
+		write(&root.join("src").join("dayNN.rs"), include_str!("dayNN.rs"))?;
+		write(&root.join("src").join("lib.rs"), LIB)?;
+		write(&root.join("src").join("y2025").join("mod.rs"), REGISTRY)?;
+
+		scaffold_day(&root, 2025, 12, "Christmas Tree Farm")?;
+		let module = read(&root.join("src").join("y2025").join("day12.rs"))?;
+		assert!(module.contains("impl Solution for Day12 {\n\tconst YEAR: u16 = 2025;\n\tconst DAY: u8 = 12;\n\tconst TITLE: &'static str = \"Christmas Tree Farm\";"));
+		assert!(module.contains("use crate::y2025::day12::*;"));
+		assert!(module.contains("include_str!(\"../../tests/examples/2025/12/example.txt\")"));
+		assert!(read(&root.join("src").join("y2025").join("mod.rs"))?.ends_with("\t&day12::Day12,\n];"));
+		assert!(root.join("tests").join("examples").join("2025").join("12").join("example.txt").is_file());
+		assert!(matches!(scaffold_day(&root, 2025, 12, "Again"), Err(Error::Scaffold(_))));
+
+		// the first day of a new year brings its registry with it
+		scaffold_day(&root, 2026, 1, "Next Year")?;
+		assert_eq!(year_module(1), read(&root.join("src").join("y2026").join("mod.rs"))?);
+		let lib = read(&root.join("src").join("lib.rs"))?;
+		assert!(lib.starts_with("pub mod y2025;\npub mod y2026;\n"));
+		assert!(lib.ends_with("\tYear { year: 2025, days: y2025::DAYS },\n\tYear { year: 2026, days: y2026::DAYS },\n];"));
+
+		fs::remove_dir_all(root).unwrap();
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,252 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::cancel::propagate;
+use crate::{count_allocations, AllocStats, Answer, Generator, Normalization, Result, Scene};
+
+pub trait Solution {
+	const YEAR: u16;
+	const DAY: u8;
+	const TITLE: &'static str;
+	const NORMALIZATION: Normalization = Normalization::DEFAULT;
+
+	// shared between threads when both parts run at once
+	type Input: Sync + 'static;
+
+	fn parse(input: &str) -> Result<Self::Input>;
+	fn part1(input: &Self::Input) -> Result<Answer>;
+	fn part2(input: &Self::Input) -> Result<Answer>;
+
+	// days whose parts share work beyond parsing override this to only do it once, wrapping that work in shared
+	// and handing the parts to both_parts, so the runner can still time everything apart
+	fn solve_both(input: &Self::Input) -> (Result<Answer>, Result<Answer>) {
+		both_parts(|| Self::part1(input), || Self::part2(input))
+	}
+
+	// days worth looking at override this, usually with their input's Render
+	fn render(_input: &Self::Input) -> Option<Scene> {
+		None
+	}
+
+	// days that can make up inputs of their own override this, for differential tests and benchmarks at any size
+	fn generator() -> Option<Generator> {
+		None
+	}
+}
+
+// object safe view of a Solution, so that every day can live in the same registry
+pub trait Day: Sync {
+	fn year(&self) -> u16;
+	fn day(&self) -> u8;
+	fn title(&self) -> &'static str;
+	fn normalization(&self) -> Normalization;
+	fn parse(&self, input: &str) -> Result<Parsed>;
+	fn solve_part1(&self, parsed: &Parsed) -> Result<Answer>;
+	fn solve_part2(&self, parsed: &Parsed) -> Result<Answer>;
+	fn solve_both(&self, parsed: &Parsed) -> (Result<Answer>, Result<Answer>);
+	fn part1(&self, input: &str) -> Result<Answer>;
+	fn part2(&self, input: &str) -> Result<Answer>;
+	fn run(&self, input: &str) -> Result<DayRun>;
+	fn run_concurrent(&self, input: &str) -> Result<DayRun>;
+	fn render(&self, parsed: &Parsed) -> Option<Scene>;
+	fn generator(&self) -> Option<Generator>;
+}
+
+// a day's parsed input with its type erased, so parsing and solving can be timed apart through the registry
+pub struct Parsed {
+	day: u8,
+	model: Box<dyn Any>,
+}
+impl Parsed {
+	pub fn day(&self) -> u8 {
+		self.day
+	}
+
+	fn model<S: Solution>(&self) -> &S::Input {
+		self.model.downcast_ref::<S::Input>()
+			.unwrap_or_else(|| panic!("day {:0>2} was given the parsed input of day {:0>2}", S::DAY, self.day))
+	}
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+	pub answer: Result<Answer>,
+	pub elapsed: Duration,
+	pub allocs: AllocStats,
+}
+
+// shared is whatever solve_both did once for both parts, and zero for days that don't share work
+#[derive(Debug)]
+pub struct DayRun {
+	pub parse: Duration,
+	pub parse_allocs: AllocStats,
+	pub shared: Duration,
+	pub shared_allocs: AllocStats,
+	pub part1: PartRun,
+	pub part2: PartRun,
+}
+impl DayRun {
+	pub fn solve(&self) -> Duration {
+		self.shared + self.part1.elapsed + self.part2.elapsed
+	}
+
+	// the whole day, with the peak of whichever stage needed the most at once
+	pub fn allocs(&self) -> AllocStats {
+		[self.shared_allocs, self.part1.allocs, self.part2.allocs].into_iter().fold(self.parse_allocs, |total, allocs| AllocStats {
+			allocations: total.allocations + allocs.allocations,
+			bytes: total.bytes + allocs.bytes,
+			peak_bytes: total.peak_bytes.max(allocs.peak_bytes),
+		})
+	}
+}
+
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration, AllocStats) {
+	let start = Instant::now();
+	let (res, allocs) = count_allocations(f);
+	(res, start.elapsed(), allocs)
+}
+
+// the stages of a solve_both the runner is timing on this thread, filled in by shared and both_parts as they go
+struct Recording {
+	concurrent: bool,
+	shared: Option<(Duration, AllocStats)>,
+	parts: Option<[(Duration, AllocStats); 2]>,
+}
+
+thread_local! {
+	static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+struct Restore(Option<Recording>);
+impl Drop for Restore {
+	fn drop(&mut self) {
+		RECORDING.set(self.0.take());
+	}
+}
+
+fn record<T>(concurrent: bool, f: impl FnOnce() -> T) -> (T, Recording) {
+	let _restore = Restore(RECORDING.replace(Some(Recording { concurrent, shared: None, parts: None })));
+	let res = f();
+	(res, RECORDING.take().expect("recording replaced while solving"))
+}
+
+// work both parts need, done once before either, and timed as its own stage when the runner is recording
+pub fn shared<T>(f: impl FnOnce() -> T) -> T {
+	if RECORDING.with_borrow(Option::is_none) {
+		return f();
+	}
+	let (res, elapsed, allocs) = measure(f);
+	RECORDING.with_borrow_mut(|recording| {
+		if let Some(recording) = recording {
+			recording.shared = Some((elapsed, allocs));
+		}
+	});
+	res
+}
+
+// both parts one after the other, unless the runner is recording, which times them apart and may put each on its own thread
+pub fn both_parts(
+	part1: impl FnOnce() -> Result<Answer> + Send,
+	part2: impl FnOnce() -> Result<Answer> + Send,
+) -> (Result<Answer>, Result<Answer>) {
+	let concurrent = RECORDING.with_borrow(|recording| recording.as_ref().map(|recording| recording.concurrent));
+	let ((answer1, elapsed1, allocs1), (answer2, elapsed2, allocs2)) = match concurrent {
+		None => return (part1(), part2()),
+		Some(false) => (measure(part1), measure(part2)),
+		Some(true) => {
+			let (part1, part2) = thread::scope(|scope| {
+				let part1 = scope.spawn(propagate(|| measure(part1)));
+				let part2 = scope.spawn(propagate(|| measure(part2)));
+				(part1.join(), part2.join())
+			});
+			match (part1, part2) {
+				(Ok(part1), Ok(part2)) => (part1, part2),
+				(Err(panic), _) | (_, Err(panic)) => std::panic::resume_unwind(panic),
+			}
+		},
+	};
+	RECORDING.with_borrow_mut(|recording| {
+		if let Some(recording) = recording {
+			recording.parts = Some([(elapsed1, allocs1), (elapsed2, allocs2)]);
+		}
+	});
+	(answer1, answer2)
+}
+
+// solve_both once with the runner recording, so days that share work between their parts only do it once here too
+fn run_day<S: Solution>(input: &str, concurrent: bool) -> Result<DayRun> {
+	let (parsed, parse, parse_allocs) = measure(|| S::parse(input));
+	let parsed = parsed?;
+
+	let (((answer1, answer2), elapsed, allocs), recording) = record(concurrent, || measure(|| S::solve_both(&parsed)));
+	// a solve_both that doesn't go through both_parts can only be timed as a whole
+	let ((shared, shared_allocs), [(elapsed1, allocs1), (elapsed2, allocs2)]) = match recording {
+		Recording { shared, parts: Some(parts), .. } => (shared.unwrap_or_default(), parts),
+		Recording { parts: None, .. } => ((elapsed, allocs), Default::default()),
+	};
+	let part1 = PartRun { answer: answer1, elapsed: elapsed1, allocs: allocs1 };
+	let part2 = PartRun { answer: answer2, elapsed: elapsed2, allocs: allocs2 };
+
+	Ok(DayRun { parse, parse_allocs, shared, shared_allocs, part1, part2 })
+}
+
+impl<S: Solution + Sync + 'static> Day for S {
+	fn year(&self) -> u16 {
+		S::YEAR
+	}
+
+	fn day(&self) -> u8 {
+		S::DAY
+	}
+
+	fn title(&self) -> &'static str {
+		S::TITLE
+	}
+
+	fn normalization(&self) -> Normalization {
+		S::NORMALIZATION
+	}
+
+	fn parse(&self, input: &str) -> Result<Parsed> {
+		let model = <S as Solution>::parse(input)?;
+		Ok(Parsed { day: S::DAY, model: Box::new(model) })
+	}
+
+	fn solve_part1(&self, parsed: &Parsed) -> Result<Answer> {
+		<S as Solution>::part1(parsed.model::<S>())
+	}
+
+	fn solve_part2(&self, parsed: &Parsed) -> Result<Answer> {
+		<S as Solution>::part2(parsed.model::<S>())
+	}
+
+	fn solve_both(&self, parsed: &Parsed) -> (Result<Answer>, Result<Answer>) {
+		<S as Solution>::solve_both(parsed.model::<S>())
+	}
+
+	fn part1(&self, input: &str) -> Result<Answer> {
+		<S as Solution>::part1(&<S as Solution>::parse(input)?)
+	}
+
+	fn part2(&self, input: &str) -> Result<Answer> {
+		<S as Solution>::part2(&<S as Solution>::parse(input)?)
+	}
+
+	fn run(&self, input: &str) -> Result<DayRun> {
+		run_day::<S>(input, false)
+	}
+
+	// the same as run, but with each part on its own thread, so they're timed and counted apart without waiting on each other
+	fn run_concurrent(&self, input: &str) -> Result<DayRun> {
+		run_day::<S>(input, true)
+	}
+
+	fn render(&self, parsed: &Parsed) -> Option<Scene> {
+		<S as Solution>::render(parsed.model::<S>())
+	}
+
+	fn generator(&self) -> Option<Generator> {
+		<S as Solution>::generator()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+// what a file looked like when last polled, None while it doesn't exist
+// the length is kept too, since some filesystems only keep modification times to the second
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+	let metadata = fs::metadata(path).ok()?;
+	Some((metadata.modified().ok()?, metadata.len()))
+}
+
+// polls a set of files for changes, including ones that don't exist yet
+#[derive(Debug, Clone)]
+pub struct Watcher {
+	files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+	pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+		Self { files: paths.into_iter().map(|path| { let stamp = stamp(&path); (path, stamp) }).collect() }
+	}
+
+	pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+		self.files.iter().map(|(path, _)| path)
+	}
+
+	// every file that was modified, created or removed since the last poll
+	pub fn poll(&mut self) -> Vec<PathBuf> {
+		let mut changed = vec![];
+		for (path, last) in &mut self.files {
+			let current = stamp(path);
+			if current != *last {
+				*last = current;
+				changed.push(path.clone());
+			}
+		}
+		changed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::env;
+	use std::fs::File;
+	use std::time::Duration;
+	use crate::watch::*;
+
+	#[test]
+	fn test_poll() {
+		let dir = env::temp_dir().join(format!("aoc25-watch-{}", std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+		fs::create_dir_all(&dir).unwrap();
+		let (input, example) = (dir.join("08.txt"), dir.join("example.txt"));
+		fs::write(&input, "162,817,812").unwrap();
+
+		let mut watcher = Watcher::new([input.clone(), example.clone()]);
+		assert!(watcher.poll().is_empty());
+
+		// the same length, so only the modification time gives it away
+		fs::write(&input, "162,817,813").unwrap();
+		let later = SystemTime::now() + Duration::from_secs(5);
+		File::options().write(true).open(&input).unwrap().set_modified(later).unwrap();
+		assert_eq!(vec![input.clone()], watcher.poll());
+		assert!(watcher.poll().is_empty());
+
+		fs::write(&example, "57,618,57").unwrap();
+		fs::remove_file(&input).unwrap();
+		assert_eq!(vec![input, example], watcher.poll());
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+}
\ No newline at end of file
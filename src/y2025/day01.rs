@@ -0,0 +1,146 @@
+#![allow(unused_imports)]
+
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::Parser;
+use nom::character::complete::{char, digit1, line_ending};
+use nom::combinator::{all_consuming, map_res, opt, verify};
+use nom::error::context;
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, terminated};
+use num::Integer;
+use crate::{explain, Answer, Error, Generator, Result, Rng, Solution};
+use crate::parse::{parse_complete, IResult};
+
+#[derive(Clone, Copy, Debug)]
+pub struct DialRotation(i64);
+
+// small enough that turning the dial this far can't overflow, wherever the dial started
+const MAX_CLICKS: i64 = i64::MAX - 100;
+
+fn parse_clicks(input: &str) -> IResult<&str, i64> {
+	context(
+		"at most 9223372036854775707 clicks",
+		verify(map_res(digit1, |num: &str| num.parse::<i64>()), |&clicks| clicks <= MAX_CLICKS)
+	).parse(input)
+}
+
+fn parse_rotation(input: &str) -> IResult<&str, DialRotation> {
+	alt((
+		preceded(char('L'), parse_clicks).map(|clicks| DialRotation(-clicks)),
+		preceded(char('R'), parse_clicks).map(DialRotation)
+	)).parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<DialRotation>> {
+	let res = parse_complete(input, terminated(
+		separated_list1(
+			line_ending,
+			parse_rotation
+		),
+		opt(line_ending)
+	))?;
+
+	Ok(res)
+}
+
+// scale is the number of rotations
+fn rotations(rng: &mut Rng, rotations: usize) -> String {
+	(0..rotations.max(1)).map(|_| {
+		let direction = if rng.chance(50) { 'L' } else { 'R' };
+		format!("{}{}", direction, rng.range(1..=999))
+	}).join("\n")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 1;
+	const TITLE: &'static str = "Secret Entrance";
+
+	type Input = Vec<DialRotation>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(rotations: &Self::Input) -> Result<Answer> {
+		const DIAL_START: i64 = 50;
+		const DIAL_SIZE: i64 = 100;
+
+		let mut dial = DIAL_START;
+		let mut dial_stopped_on_zero_count: u64 = 0;
+		for &DialRotation(rotation) in rotations {
+			dial = (dial + rotation) % DIAL_SIZE;
+			if dial == 0 { dial_stopped_on_zero_count += 1 }
+			explain!(Detail, "rotation", rotation = rotation, dial = dial.rem_euclid(DIAL_SIZE));
+		}
+
+		Ok(dial_stopped_on_zero_count.into())
+	}
+
+	fn part2(rotations: &Self::Input) -> Result<Answer> {
+		const DIAL_START: i64 = 50;
+		const DIAL_SIZE: i64 = 100;
+
+		let mut dial = DIAL_START;
+		let mut dial_touched_zero_count: u64 = 0;
+		for &DialRotation(rotation) in rotations {
+			let previous_count = dial_touched_zero_count;
+
+			let (zero_passes, new_dial) = (dial + rotation).div_rem(&DIAL_SIZE);
+
+			// landing on or passing zero once more than the whole turns
+			let extra_touch = if new_dial >= 0 {
+				new_dial == 0 && rotation.is_negative()
+			} else {
+				dial != 0
+			};
+			dial_touched_zero_count = dial_touched_zero_count.checked_add(zero_passes.unsigned_abs() + extra_touch as u64)
+				.ok_or_else(|| Error::overflow("counting the times the dial touched zero"))?;
+			dial = new_dial.rem_euclid(DIAL_SIZE);
+
+			explain!(Detail, "rotation", rotation = rotation, dial = dial, zeros = dial_touched_zero_count - previous_count);
+		}
+
+		Ok(dial_touched_zero_count.into())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 4000, generate: rotations })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day01::part1(&Day01::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day01::part2(&Day01::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day01::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/01/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(3, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(6, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_error() {
+		let err = part1("L68\nL30\nX48").unwrap_err();
+		assert_eq!("failed to parse input: expected 'L' or 'R' at line 3, column 1\n  |\n3 | X48\n  | ^", err.to_string());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,184 @@
+#![allow(unused_imports)]
+
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use ahash::AHashSet;
+use itertools::Itertools;
+use nom::character::complete::{char, line_ending, u64};
+use nom::combinator::{all_consuming, map_res, opt};
+use nom::multi::separated_list1;
+use nom::Parser;
+use nom::sequence::{separated_pair, terminated};
+use num::Integer;
+use crate::{explain, Answer, Error, Generator, Result, Rng, Solution};
+use crate::cancel::check;
+use crate::parse::{parse_complete, IResult};
+
+fn parse_range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
+	separated_pair(u64, char('-'), u64)
+		.map(|(start, end)| start..=end)
+		.parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<RangeInclusive<u64>>> {
+	let res = parse_complete(input,
+		terminated(
+			separated_list1(
+				char(','),
+				parse_range
+			),
+			opt(line_ending)
+		)
+	)?;
+
+	Ok(res)
+}
+
+fn parse_repeated(prefix: &str, repetitions: usize) -> Result<u64> {
+	prefix.repeat(repetitions).parse::<u64>().map_err(|_|
+		Error::overflow(format!("'{}' repeated {} times does not fit in a u64", prefix, repetitions))
+	)
+}
+
+// scale is the number of ranges, which never overlap
+fn id_ranges(rng: &mut Rng, ranges: usize) -> String {
+	const MAX_ID: u64 = 9_999_999_999;
+
+	let starts = (0..ranges.max(1)).map(|_| {
+		let digits = rng.range(1..=10) as u32;
+		rng.range(10u64.pow(digits - 1)..=(10u64.pow(digits) - 1))
+	}).collect::<BTreeSet<_>>().into_iter().collect_vec();
+
+	let mut ranges = starts.iter().enumerate().map(|(ix, &start)| {
+		let limit = starts.get(ix + 1).map_or(MAX_ID, |next| next - 1);
+		let end = start.saturating_add(rng.range(0..=(start / 10).min(100_000))).min(limit);
+		format!("{}-{}", start, end)
+	}).collect_vec();
+	rng.shuffle(&mut ranges);
+	ranges.join(",")
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 2;
+	const TITLE: &'static str = "Gift Shop";
+
+	type Input = Vec<RangeInclusive<u64>>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(ranges: &Self::Input) -> Result<Answer> {
+		let mut invalid_id_sum: u64 = 0;
+		for range in ranges {
+			let range_start = *range.start();
+			let range_end = *range.end();
+			let range_start_string = range_start.to_string();
+
+			let start_len = range_start_string.len();
+			let invalid_start = if start_len.is_even() {
+				// number can be evenly chopped into two lexicographic halves
+				String::from(&range_start_string[..(start_len/2)])
+			} else {
+				// only numbers with an even amount of digits can be invalid
+				// the smallest number that would be greater than the range start...
+				// would be 1 followed by (start_len/2) number of 0's
+				String::from("1") + &String::from("0").repeat(start_len/2)
+			};
+
+			// may start checking below the range start, incorrectly leading to an early exit
+			let mut invalid_prefix = if parse_repeated(&invalid_start, 2)? < range_start {
+				(parse_repeated(&invalid_start, 1)? + 1).to_string()
+			} else {
+				invalid_start
+			};
+
+			loop {
+				check(|| format!("range {}-{}", range_start, range_end))?;
+				let invalid_num = parse_repeated(&invalid_prefix, 2)?;
+				if invalid_num > range_end {
+					break;
+				}
+				invalid_id_sum = invalid_id_sum.checked_add(invalid_num)
+					.ok_or_else(|| Error::overflow(format!("adding invalid id {} to the sum", invalid_num)))?;
+				explain!(Detail, "invalid id", id = invalid_num, range = format!("{}-{}", range_start, range_end));
+				invalid_prefix = parse_repeated(&invalid_prefix, 1)?.checked_add(1)
+					.ok_or_else(|| Error::overflow(format!("the prefix after {}", invalid_prefix)))?
+					.to_string();
+			}
+		}
+
+		Ok(invalid_id_sum.into())
+	}
+
+	fn part2(ranges: &Self::Input) -> Result<Answer> {
+		// no ranges means nothing to find
+		let Some(max_range_end) = ranges.iter().map(|r| *r.end()).max() else {
+			return Ok(0u64.into());
+		};
+
+		let mut invalid_sum: u64 = 0;
+		let mut checked_nums = AHashSet::new();
+		let mut invalid_prefix_num = 1;
+		loop{
+			check(|| format!("candidate prefix {}", invalid_prefix_num))?;
+			let invalid_prefix = invalid_prefix_num.to_string();
+			let min_invalid_num = parse_repeated(&invalid_prefix, 2)?;
+			if min_invalid_num > max_range_end {
+				break;
+			}
+
+			for repetitions in 2.. {
+				let invalid_num = parse_repeated(&invalid_prefix, repetitions)?;
+				if invalid_num > max_range_end || checked_nums.contains(&invalid_num) {
+					break;
+				}
+
+				checked_nums.insert(invalid_num);
+				if let Some(range) = ranges.iter().find(|r| r.contains(&invalid_num)) {
+					invalid_sum = invalid_sum.checked_add(invalid_num)
+						.ok_or_else(|| Error::overflow(format!("adding invalid id {} to the sum", invalid_num)))?;
+					explain!(Detail, "invalid id", id = invalid_num, range = format!("{}-{}", range.start(), range.end()));
+				}
+			}
+
+			invalid_prefix_num += 1;
+		}
+
+		Ok(invalid_sum.into())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 35, generate: id_ranges })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day02::part1(&Day02::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day02::part2(&Day02::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day02::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/02/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(1227775554, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(4174379265, part2(TEST)?);
+		Ok(())
+	}
+}
\ No newline at end of file
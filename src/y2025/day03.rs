@@ -0,0 +1,176 @@
+#![allow(unused_imports)]
+
+use std::cmp::max;
+use itertools::Itertools;
+use nom::character::complete::{line_ending, satisfy};
+use nom::Parser;
+use nom::combinator::{all_consuming, opt};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::terminated;
+use crate::{explain, Answer, Error, Generator, Result, Rng, Solution};
+use crate::parse::{parse_complete, IResult};
+
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Joltage(u32);
+
+#[derive(Clone, Debug)]
+pub struct BatteryBank(Vec<Joltage>);
+
+fn parse_joltage(input: &str) -> IResult<&str, Joltage> {
+	satisfy(nom::AsChar::is_dec_digit)
+		.map_opt(|chr| chr.to_digit(10))
+		.map(Joltage)
+		.parse(input)
+}
+
+fn parse_battery_bank(input: &str) -> IResult<&str, BatteryBank> {
+	many1(parse_joltage)
+		.map(BatteryBank)
+		.parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<BatteryBank>> {
+	let res = parse_complete(input, terminated(
+		separated_list1(
+			line_ending,
+			parse_battery_bank
+		),
+		opt(line_ending)
+	))?;
+
+	Ok(res)
+}
+
+// scale is the number of banks of 100 batteries
+fn battery_banks(rng: &mut Rng, banks: usize) -> String {
+	(0..banks.max(1)).map(|_| {
+		(0..100).map(|_| char::from(b'0' + rng.range(1..=9) as u8)).collect::<String>()
+	}).join("\n")
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 3;
+	const TITLE: &'static str = "Lobby";
+
+	type Input = Vec<BatteryBank>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(battery_banks: &Self::Input) -> Result<Answer> {
+		check_bank_sizes(battery_banks, 2)?;
+		let total_joltage: u32 = battery_banks.iter().enumerate().map(|(bank_ix, BatteryBank(bank))| {
+			// find max joltage in the bank, excluding the last battery
+			// short circuit for the first 9 joltage battery found
+			let (mut max_ix, mut max_joltage) = (0, 0);
+			for (ix, &Joltage(j)) in bank[..(bank.len() - 1)].iter().enumerate() {
+				if j > max_joltage {
+					max_ix = ix;
+					max_joltage = j;
+				}
+				if max_joltage == 9 {
+					break;
+				}
+			}
+			let joltage = (max_joltage * 10) + bank[(max_ix + 1)..].iter().max().map(|Joltage(j)| j).unwrap();
+			explain!(Detail, "bank", bank = bank_ix, joltage = joltage);
+			joltage
+		}).sum();
+
+		Ok(total_joltage.into())
+	}
+
+	fn part2(battery_banks: &Self::Input) -> Result<Answer> {
+		const BATTERIES_PER_BANK: usize = 12;
+		check_bank_sizes(battery_banks, BATTERIES_PER_BANK)?;
+
+		let total_joltage: u64 = battery_banks.iter().enumerate()
+			.map(|(bank_ix, BatteryBank(bank))| {
+				let joltage = get_max_joltage(bank, BATTERIES_PER_BANK);
+				explain!(Detail, "bank", bank = bank_ix, joltage = joltage);
+				joltage
+			})
+			.sum();
+
+		Ok(total_joltage.into())
+	}
+
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 200, generate: battery_banks })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day03::part1(&Day03::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day03::part2(&Day03::parse(input)?)
+}
+
+// every bank has to have enough batteries to turn on, or picking them runs off the end
+fn check_bank_sizes(battery_banks: &[BatteryBank], batteries_to_use: usize) -> Result<()> {
+	match battery_banks.iter().position(|BatteryBank(bank)| bank.len() < batteries_to_use) {
+		Some(bank_ix) => Err(Error::no_solution(format!("bank #{} has fewer than {} batteries", bank_ix, batteries_to_use))),
+		None => Ok(()),
+	}
+}
+
+fn get_max_joltage(
+	battery_bank: &[Joltage],
+	batteries_to_use: usize,
+) -> u64 {
+	fn get_max_joltage_rec(
+		remaining_bank: &[Joltage],
+		remaining_batteries: usize,
+		accumulated_joltage: u64
+	) -> u64 {
+		if remaining_batteries == 0 {
+			return accumulated_joltage;
+		}
+
+		let selectable_bank = &remaining_bank[..(remaining_bank.len() - remaining_batteries + 1)];
+		let (mut max_ix, mut max_joltage) = (0, 0);
+		for (ix, &Joltage(j)) in selectable_bank.iter().enumerate() {
+			if j > max_joltage {
+				max_ix = ix;
+				max_joltage = j;
+			}
+			if max_joltage == 9 {
+				break;
+			}
+		}
+
+		get_max_joltage_rec(
+			&remaining_bank[(max_ix + 1)..],
+			remaining_batteries - 1,
+			(accumulated_joltage * 10) + max_joltage as u64
+		)
+	}
+
+	get_max_joltage_rec(battery_bank, batteries_to_use, 0)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day03::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/03/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(357, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(3121910778619, part2(TEST)?);
+		Ok(())
+	}
+}
\ No newline at end of file
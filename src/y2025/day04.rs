@@ -0,0 +1,277 @@
+#![allow(unused_imports)]
+
+use std::collections::VecDeque;
+use grid::Grid;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending};
+use nom::combinator::{all_consuming, opt, value};
+use nom::Parser;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::terminated;
+use crate::{explain, Answer, Color, Error, Generator, Render, Result, Rng, Scene, Shape, Solution};
+use crate::parse::{parse_complete, rectangular, IResult};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Cell {
+	Empty,
+	PaperRoll
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Direction {
+	North,
+	Northeast,
+	East,
+	Southeast,
+	South,
+	Southwest,
+	West,
+	Northwest
+}
+impl Direction {
+	pub fn get_offset(&self) -> (isize, isize) {
+		match self {
+			Direction::North => (0, -1),
+			Direction::Northeast => (1, -1),
+			Direction::East => (1, 0),
+			Direction::Southeast => (1, 1),
+			Direction::South => (0, 1),
+			Direction::Southwest => (-1, 1),
+			Direction::West => (-1, 0),
+			Direction::Northwest => (-1, -1),
+		}
+	}
+	pub fn iter() -> impl Iterator<Item = Direction> {
+		[
+			Direction::North,
+			Direction::Northeast,
+			Direction::East,
+			Direction::Southeast,
+			Direction::South,
+			Direction::Southwest,
+			Direction::West,
+			Direction::Northwest
+		].into_iter()
+	}
+}
+
+fn parse_cell(input: &str) -> IResult<&str, Cell> {
+	alt((
+		value(Cell::Empty, char('.')),
+		value(Cell::PaperRoll, char('@'))
+	)).parse(input)
+}
+
+fn parse_row(input: &str) -> IResult<&str, Vec<Cell>> {
+	terminated(
+		many1(parse_cell),
+		opt(line_ending)
+	).parse(input)
+}
+
+fn parse(input: &str) -> Result<Grid<Cell>> {
+	let res = parse_complete(input, many1(parse_row))?;
+
+	rectangular(input, res, "cells")
+}
+
+fn adjacent_rolls(warehouse_map: &Grid<Cell>, row: usize, col: usize) -> usize {
+	Direction::iter().map(|d| d.get_offset())
+		.filter(|&(row_offset, col_offset)| {
+			let adjacent = warehouse_map.get(row_offset.strict_add_unsigned(row), col_offset.strict_add_unsigned(col));
+			adjacent == Some(&Cell::PaperRoll)
+		})
+		.count()
+}
+
+// the round each roll of paper goes in if every accessible roll is removed at once, for rolls that go at all
+fn removal_rounds(warehouse_map: &Grid<Cell>) -> Grid<Option<usize>> {
+	let mut warehouse_map = warehouse_map.clone();
+	let mut rounds = Grid::init(warehouse_map.rows(), warehouse_map.cols(), None);
+	for round in 0.. {
+		let accessible = warehouse_map.indexed_iter()
+			.filter(|&((row, col), &cell)| cell == Cell::PaperRoll && adjacent_rolls(&warehouse_map, row, col) < 4)
+			.map(|(grid_coords, _)| grid_coords)
+			.collect_vec();
+		if accessible.is_empty() {
+			break;
+		}
+		for grid_coords in accessible {
+			warehouse_map[grid_coords] = Cell::Empty;
+			rounds[grid_coords] = Some(round);
+		}
+	}
+	rounds
+}
+
+impl Render for Grid<Cell> {
+	// rolls accessible straight away are x, rolls freed up later fade from red to yellow, and rolls that stay are @
+	fn render(&self) -> Scene {
+		const ACCESSIBLE: Color = Color(220, 30, 30);
+		const LAST_REMOVED: Color = Color(240, 200, 40);
+
+		let rounds = removal_rounds(self);
+		let last_round = rounds.iter().flatten().copied().max().unwrap_or(0).max(1);
+		let mut scene = Scene::new(self.cols() as u64, self.rows() as u64);
+		for ((row, col), &cell) in self.indexed_iter() {
+			let (glyph, color) = match (cell, rounds[(row, col)]) {
+				(Cell::Empty, _) => ('.', Color(235, 235, 235)),
+				(Cell::PaperRoll, Some(0)) => ('x', ACCESSIBLE),
+				(Cell::PaperRoll, Some(round)) => ('o', ACCESSIBLE.blend(LAST_REMOVED, round as f64 / last_round as f64)),
+				(Cell::PaperRoll, None) => ('@', Color(90, 90, 90)),
+			};
+			scene.push(Shape::Cell { x: col as u64, y: row as u64, glyph, color });
+		}
+		scene
+	}
+}
+
+// scale is the side of the square grid
+fn paper_grid(rng: &mut Rng, side: usize) -> String {
+	(0..side.max(1)).map(|_| {
+		(0..side.max(1)).map(|_| if rng.chance(60) { '@' } else { '.' }).collect::<String>()
+	}).join("\n")
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 4;
+	const TITLE: &'static str = "Printing Department";
+
+	type Input = Grid<Cell>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(warehouse_map: &Self::Input) -> Result<Answer> {
+		let accessible_rolls_of_paper: u64 = warehouse_map.indexed_iter().map(|((row, col), &cell)| {
+			if cell != Cell::PaperRoll {
+				return 0;
+			}
+
+			// a roll of paper is accessible if:
+			// there are FEWER than FOUR rolls of paper in the eight adjacent cells
+			let mut adjacent_papers = 0;
+			for (row_offset, col_offset) in Direction::iter().map(|d| d.get_offset()) {
+				if let Some(Cell::PaperRoll) = warehouse_map.get(row_offset.strict_add_unsigned(row), col_offset.strict_add_unsigned(col)) {
+					adjacent_papers += 1;
+				}
+				if adjacent_papers >= 4 {
+					break;
+				}
+			}
+
+			if adjacent_papers < 4 {
+				explain!(Detail, "accessible", row = row, col = col);
+				1
+			} else {
+				0
+			}
+		}).sum();
+
+		Ok(accessible_rolls_of_paper.into())
+	}
+
+	fn part2(warehouse_map: &Self::Input) -> Result<Answer> {
+		let mut warehouse_map = warehouse_map.clone();
+
+		// locate all paper rolls which must be checked for forklift accessibility
+		let mut locations_to_check = warehouse_map.indexed_iter().filter_map(|(grid_coords, &cell)| {
+			match cell {
+				Cell::Empty => None,
+				Cell::PaperRoll => Some(grid_coords),
+			}
+		}).collect::<VecDeque<_>>();
+
+		let mut accessible_rolls_of_paper: u64 = 0;
+		while let Some((row, col)) = locations_to_check.pop_front() {
+			// double check that this location still contains a roll of paper,
+			// as it may have been removed already
+			if *warehouse_map.get(row, col).unwrap() != Cell::PaperRoll {
+				continue;
+			}
+
+			// cache adjacent paper roll coordinates in case this roll is removed
+			// initialize with capacity 4 as once the fourth roll is inserted,
+			// this roll is inaccessible anyway and will not be removed
+			let mut adjacent_papers = Vec::with_capacity(4);
+			let mut adjacent_papers_count = 0;
+			for (row_offset, col_offset) in Direction::iter().map(|d| d.get_offset()) {
+				let adj_row = row_offset.strict_add_unsigned(row);
+				let adj_col = col_offset.strict_add_unsigned(col);
+				if let Some(Cell::PaperRoll) = warehouse_map.get(adj_row, adj_col) {
+					adjacent_papers_count += 1;
+					adjacent_papers.push((adj_row as usize, adj_col as usize));
+				}
+				if adjacent_papers_count >= 4 {
+					break;
+				}
+			}
+
+			if adjacent_papers_count < 4 {
+				accessible_rolls_of_paper += 1;
+				explain!(Detail, "removed", row = row, col = col, adjacent = adjacent_papers_count);
+				// remove this accessible roll of paper from the warehouse
+				*warehouse_map.get_mut(row, col).unwrap() = Cell::Empty;
+				// queue neighboring rolls of paper to be checked for accessibility again
+				locations_to_check.append(&mut adjacent_papers.into());
+			}
+		}
+
+		Ok(accessible_rolls_of_paper.into())
+	}
+
+	fn render(warehouse_map: &Self::Input) -> Option<Scene> {
+		Some(warehouse_map.render())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 135, generate: paper_grid })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day04::part1(&Day04::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day04::part2(&Day04::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day04::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/04/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(13, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(43, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_render() -> Result<()> {
+		let scene = Day04::parse(TEST)?.render();
+		let first_row = scene.shapes.iter().take(10).map(|shape| match shape {
+			Shape::Cell { glyph, .. } => *glyph,
+			_ => unreachable!(),
+		}).collect::<String>();
+		// the roll at column 7 isn't accessible until its neighbours go
+		assert_eq!("..xx.xxox.", first_row);
+		// the rolls marked as removed are the ones part 2 counts
+		let removed = scene.shapes.iter().filter(|shape| matches!(shape, Shape::Cell { glyph: 'x' | 'o', .. })).count();
+		assert_eq!(43, removed);
+		Ok(())
+	}
+}
\ No newline at end of file
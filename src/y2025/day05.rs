@@ -0,0 +1,208 @@
+#![allow(unused_imports)]
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use ahash::AHashMap;
+use itertools::Itertools;
+use nom::character::complete::{char, line_ending, u64};
+use nom::Parser;
+use nom::combinator::{all_consuming, opt};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{separated_pair, terminated};
+use crate::{explain, Answer, Error, Generator, Result, Rng, Solution};
+use crate::parse::{parse_complete, IResult, ParseDiagnostic};
+
+fn parse_range(input: &str) -> IResult<&str, RangeInclusive<u64>> {
+	separated_pair(u64, char('-'), u64)
+		.map(|(start, end)| start..=end)
+		.parse(input)
+}
+
+fn parse_ranges(input: &str) -> IResult<&str, Vec<RangeInclusive<u64>>> {
+	many1(terminated(parse_range, line_ending)).parse(input)
+}
+
+fn parse_ingredients(input: &str) -> IResult<&str, Vec<u64>> {
+	many1(
+		terminated(
+			u64,
+			opt(line_ending)
+		)
+	).parse(input)
+}
+
+fn parse(input: &str) -> Result<(Vec<RangeInclusive<u64>>, Vec<u64>)> {
+	let res = parse_complete(input,
+		separated_pair(
+			parse_ranges,
+			line_ending,
+			parse_ingredients
+		)
+	)?;
+
+	// the ranges come first, one to a line
+	if let Some(line_ix) = res.0.iter().position(|range| range.start() > range.end()) {
+		return Err(Error::Parse(ParseDiagnostic::at(input, line_ix, 0, "a range that doesn't end before it starts")));
+	}
+	Ok(res)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum FreshnessChange {
+	Start,
+	End
+}
+
+// scale is the number of fresh ranges, with five ingredients per range
+fn ingredient_database(rng: &mut Rng, ranges: usize) -> String {
+	const MAX_ID: u64 = 550_000_000_000_000;
+
+	let mut fresh_ranges: Vec<(u64, u64)> = vec![];
+	for _ in 0..ranges.max(1) {
+		let width = rng.range(0..=10_000_000_000_000);
+		// some ranges start where an earlier one starts or ends, or right after it, or are a single id
+		let range = match (fresh_ranges.is_empty(), rng.range(0..=9)) {
+			(false, variant @ 0..=3) => {
+				let (start, end) = fresh_ranges[rng.index(fresh_ranges.len())];
+				match variant {
+					0 => (start, start + width),
+					1 => (end, end + width),
+					2 => (end + 1, end + 1 + width),
+					_ => (end, end),
+				}
+			},
+			_ => {
+				let start = rng.range(1..=MAX_ID);
+				(start, start + width)
+			},
+		};
+		fresh_ranges.push((range.0.min(MAX_ID), range.1.min(MAX_ID)));
+	}
+	let fresh_ranges = fresh_ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).join("\n");
+	let ingredients = (0..(ranges.max(1) * 5)).map(|_| rng.range(1..=MAX_ID).to_string()).join("\n");
+
+	format!("{}\n\n{}", fresh_ranges, ingredients)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 5;
+	const TITLE: &'static str = "Cafeteria";
+
+	type Input = (Vec<RangeInclusive<u64>>, Vec<u64>);
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1((freshness_ranges, ingredients): &Self::Input) -> Result<Answer> {
+		let fresh_ingredients = ingredients.iter()
+			.filter(|&ingredient| {
+				let fresh_range = freshness_ranges.iter().find(|range| range.contains(ingredient));
+				if let Some(range) = fresh_range {
+					explain!(Detail, "fresh", id = ingredient, range = format!("{}-{}", range.start(), range.end()));
+				}
+				fresh_range.is_some()
+			}).count();
+
+		Ok(fresh_ingredients.into())
+	}
+
+	fn part2((freshness_ranges, _): &Self::Input) -> Result<Answer> {
+		let mut consolidated_fresh_ranges = BTreeMap::new();
+		freshness_ranges.iter().flat_map(|fresh_range| vec![
+			(fresh_range.start(), FreshnessChange::Start),
+			(fresh_range.end(), FreshnessChange::End)
+		]).for_each(|(ingredient_id, freshness)| {
+			consolidated_fresh_ranges.entry(ingredient_id)
+				.or_insert(vec![])
+				.push(freshness);
+		});
+
+		let mut fresh_ingredient_id_count: u64 = 0;
+		let mut active_freshness_ranges = 0;
+		let mut freshness_start_id = None;
+		consolidated_fresh_ranges.into_iter().for_each(|(ingredient_id, freshness_changes)| {
+			let was_fresh = active_freshness_ranges > 0;
+			active_freshness_ranges += freshness_changes.iter().map(|freshness| {
+				match freshness {
+					FreshnessChange::Start => 1,
+					FreshnessChange::End => -1,
+				}
+			}).sum::<i32>();
+			if was_fresh {
+				if active_freshness_ranges == 0 {
+					assert_ne!(freshness_start_id, None);
+					let start_id: &u64 = freshness_start_id.unwrap();
+					fresh_ingredient_id_count += ingredient_id - start_id + 1;
+					explain!(Summary, "merged range", start = start_id, end = ingredient_id, ids = ingredient_id - start_id + 1);
+					freshness_start_id = None;
+				}
+			} else {
+				if active_freshness_ranges > 0 {
+					assert_eq!(freshness_start_id, None);
+					freshness_start_id = Some(ingredient_id);
+				} else {
+					// wasn't fresh before but still isn't fresh now
+					// range of 1
+					fresh_ingredient_id_count += 1;
+					explain!(Summary, "merged range", start = ingredient_id, end = ingredient_id, ids = 1);
+				}
+			}
+		});
+
+		Ok(fresh_ingredient_id_count.into())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 180, generate: ingredient_database })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day05::part1(&Day05::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day05::part2(&Day05::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day05::*;
+	use crate::{collect_events, Event, Verbosity};
+
+	const TEST: &str = include_str!("../../tests/examples/2025/05/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(3, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(14, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_explain() -> Result<()> {
+		let (answer, events) = collect_events(Verbosity::Summary, || part2(TEST));
+		assert_eq!(14, answer?);
+		assert_eq!(
+			vec!["merged range start=3 end=5 ids=3", "merged range start=10 end=20 ids=11"],
+			events.iter().map(Event::to_string).collect::<Vec<_>>()
+		);
+
+		// fresh ingredients are only reported in detail
+		let (_, events) = collect_events(Verbosity::Summary, || part1(TEST));
+		assert!(events.is_empty());
+		let (_, events) = collect_events(Verbosity::Detail, || part1(TEST));
+		assert_eq!(3, events.len());
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,238 @@
+#![allow(unused_imports)]
+use nom::Parser;
+use grid::Grid;
+use itertools::{izip, Itertools};
+use nom::branch::alt;
+use nom::bytes::complete::take_until1;
+use nom::character::complete::{char, line_ending, satisfy, space0, space1, u64};
+use nom::combinator::{all_consuming, opt, value};
+use nom::multi::{many1, many1_count};
+use nom::sequence::{pair, preceded, terminated};
+use crate::{explain, Answer, Error, Generator, Normalization, Result, Rng, Solution};
+use crate::parse::{parse_complete, rectangular, IResult, ParseDiagnostic};
+
+fn parse_number_row(input: &str) -> IResult<&str, Vec<u64>> {
+	terminated(
+		many1(preceded(space0, u64)),
+		pair(space0, line_ending)
+	).parse(input)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MathOp {
+	Add,
+	Mul
+}
+impl MathOp {
+	fn apply(self, operands: impl IntoIterator<Item = Result<u64>>) -> Result<u64> {
+		let identity = match self {
+			MathOp::Add => 0,
+			MathOp::Mul => 1,
+		};
+		operands.into_iter().try_fold(identity, |acc: u64, operand| {
+			let operand = operand?;
+			match self {
+				MathOp::Add => acc.checked_add(operand),
+				MathOp::Mul => acc.checked_mul(operand),
+			}.ok_or_else(|| Error::overflow(match self {
+				MathOp::Add => "adding up a problem's numbers",
+				MathOp::Mul => "multiplying a problem's numbers",
+			}))
+		})
+	}
+}
+
+fn checked_total(solutions: impl IntoIterator<Item = Result<u64>>) -> Result<u64> {
+	solutions.into_iter().try_fold(0u64, |total, solution| {
+		total.checked_add(solution?).ok_or_else(|| Error::overflow("adding up the worksheet"))
+	})
+}
+
+fn parse_operation_row(input: &str) -> IResult<&str, Vec<MathOp>> {
+	terminated(
+		many1(terminated(
+			alt((
+				value(MathOp::Add, char('+')),
+				value(MathOp::Mul, char('*'))
+			)),
+			space0
+		)),
+		opt(line_ending)
+	).parse(input)
+}
+
+fn parse_part1(input: &str) -> Result<(Vec<Vec<u64>>, Vec<MathOp>)> {
+	let res = parse_complete(input, pair(
+		many1(parse_number_row),
+		parse_operation_row
+	))?;
+
+	Ok(res)
+}
+
+fn parse_digit_line(input: &str) -> IResult<&str, Vec<char>> {
+	terminated(
+		many1(alt((
+			satisfy(nom::AsChar::is_space),
+			satisfy(nom::AsChar::is_dec_digit)
+		))),
+		line_ending
+	).parse(input)
+}
+
+// an operation followed by the width of its column
+type SizedOp = (MathOp, usize);
+
+fn parse_operation_line(input: &str) -> IResult<&str, Vec<SizedOp>> {
+	terminated(
+		many1(pair(
+			alt((
+				value(MathOp::Add, char('+')),
+				value(MathOp::Mul, char('*'))
+			)),
+			many1_count(char(' '))
+		)),
+		opt(line_ending)
+	).parse(input)
+}
+
+fn parse_part2(input: &str) -> Result<(Vec<Vec<char>>, Vec<SizedOp>)> {
+	let res = parse_complete(input, pair(
+		many1(parse_digit_line),
+		parse_operation_line
+	))?;
+
+	Ok(res)
+}
+
+pub struct Worksheet {
+	number_grid: Grid<u64>,
+	operation_row: Vec<MathOp>,
+	digit_grid: Grid<char>,
+	sized_ops: Vec<SizedOp>,
+}
+
+// the two parts read the worksheet differently, so keep both interpretations
+fn parse(input: &str) -> Result<Worksheet> {
+	let (number_rows, operation_row) = parse_part1(input)?;
+	let (digit_rows, sized_ops) = parse_part2(input)?;
+	let number_grid = rectangular(input, number_rows, "numbers")?;
+	let digit_grid = rectangular(input, digit_rows, "characters")?;
+
+	Ok(Worksheet { number_grid, operation_row, digit_grid, sized_ops })
+}
+
+// scale is the number of problems, each a column of four numbers under one operator
+fn worksheet(rng: &mut Rng, problems: usize) -> String {
+	const ROWS: usize = 4;
+
+	let mut number_rows = vec![vec![]; ROWS];
+	let mut operation_row = vec![];
+	for ix in 0..problems.max(1) {
+		let mut numbers = (0..ROWS).map(|_| rng.range(1..=999).to_string()).collect_vec();
+		let width = numbers.iter().map(String::len).max().unwrap_or(1);
+		// the digits of a problem all hang from the left or all from the right, and sorting by length
+		// keeps every column's digits together so it still reads as one number
+		numbers.sort_by_key(String::len);
+		if rng.chance(50) {
+			numbers.reverse();
+		}
+		let right_aligned = rng.chance(50);
+		for (row, number) in number_rows.iter_mut().zip(numbers) {
+			row.push(if right_aligned { format!("{:>width$}", number) } else { format!("{:<width$}", number) });
+		}
+
+		let op = if rng.chance(50) { '+' } else { '*' };
+		let padding = if ix == problems.max(1) - 1 { width - 1 } else { width };
+		operation_row.push(format!("{}{}", op, " ".repeat(padding)));
+	}
+
+	number_rows.iter().map(|row| row.join(" ")).chain([operation_row.concat()]).join("\n")
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 6;
+	const TITLE: &'static str = "Trash Compactor";
+	// columns are aligned with spaces, so lines must keep their trailing padding
+	const NORMALIZATION: Normalization = Normalization {
+		trim_trailing_whitespace: false,
+		..Normalization::DEFAULT
+	};
+
+	type Input = Worksheet;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(worksheet: &Self::Input) -> Result<Answer> {
+		let res = checked_total(izip!(worksheet.number_grid.iter_cols(), &worksheet.operation_row).map(|(col_nums, op)| {
+			let problem_solution = op.apply(col_nums.map(|&num| Ok(num)))?;
+			explain!(Detail, "problem", op = format!("{:?}", op), solution = problem_solution);
+			Ok(problem_solution)
+		}))?;
+
+		Ok(res.into())
+	}
+
+	fn part2(worksheet: &Self::Input) -> Result<Answer> {
+		let mut digit_grid = worksheet.digit_grid.iter_cols().rev();
+	
+		let mut sized_ops = worksheet.sized_ops.iter().copied().rev();
+	
+		let mut process_sized_op = |(op, operand_size): SizedOp| -> Result<u64> {
+			let operands = digit_grid.by_ref().take(operand_size)
+				.map(|digits| {
+					let digit_str = digits.collect::<String>();
+					digit_str.trim().parse::<u64>()
+						.map_err(|_| Error::Parse(ParseDiagnostic::expected(format!("a number in column '{}'", digit_str))))
+				});
+			let problem_solution = op.apply(operands)?;
+			digit_grid.next();
+			explain!(Detail, "problem", op = format!("{:?}", op), width = operand_size, solution = problem_solution);
+			Ok(problem_solution)
+		};
+
+		// the last problem has no blank column after it to count in its size
+		let (first_op, first_op_size) = sized_ops.next()
+			.ok_or_else(|| Error::Parse(ParseDiagnostic::expected("an operator row with at least one operator")))?;
+		let first_solution = process_sized_op((first_op, first_op_size + 1));
+		let worksheet_sum = checked_total([first_solution].into_iter().chain(sized_ops.map(process_sized_op)))?;
+	
+		Ok(worksheet_sum.into())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 1000, generate: worksheet })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day06::part1(&Day06::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day06::part2(&Day06::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day06::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/06/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(4277556, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(3263827, part2(TEST)?);
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,246 @@
+#![allow(unused_imports)]
+
+use ahash::AHashSet;
+use grid::Grid;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending};
+use nom::combinator::{all_consuming, opt, value};
+use nom::Parser;
+use nom::multi::many1;
+use nom::sequence::terminated;
+use crate::{explain, Answer, Color, Error, Generator, Render, Result, Rng, Scene, Shape, Solution};
+use crate::parse::{parse_complete, rectangular, IResult, ParseDiagnostic};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Cell {
+	Start,
+	Splitter,
+	Empty
+}
+
+fn parse_cell(input: &str) -> IResult<&str, Cell> {
+	alt((
+		value(Cell::Start, char('S')),
+		value(Cell::Splitter, char('^')),
+		value(Cell::Empty, char('.')),
+	)).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<Cell>> {
+	terminated(
+		many1(parse_cell),
+		opt(line_ending)
+	).parse(input)
+}
+
+fn parse(input: &str) -> Result<Grid<Cell>> {
+	let res = parse_complete(input, many1(parse_line))?;
+
+	let grid = rectangular(input, res, "cells")?;
+
+	// beams only ever start from the top row
+	if let Some(((row, col), _)) = grid.indexed_iter().find(|&((row, _), &cell)| row > 0 && cell == Cell::Start) {
+		return Err(Error::Parse(ParseDiagnostic::at(input, row, col, "'^' or '.' below the first row")));
+	}
+	Ok(grid)
+}
+
+// how many timelines pass through each cell, following the beams down from the start
+fn beam_timelines(grid: &Grid<Cell>) -> Grid<u64> {
+	let mut timelines = Grid::init(grid.rows(), grid.cols(), 0u64);
+	for (col, &cell) in grid.iter_row(0).enumerate() {
+		if cell == Cell::Start {
+			timelines[(0, col)] = 1;
+		}
+	}
+
+	for row in 1..grid.rows() {
+		for col in 0..grid.cols() {
+			let above = timelines[(row - 1, col)];
+			match grid[(row, col)] {
+				// beams split off either side of the manifold are lost
+				Cell::Splitter => {
+					for side in [col as isize - 1, col as isize + 1] {
+						if let Some(beside) = timelines.get_mut(row, side) {
+							*beside += above;
+						}
+					}
+				},
+				_ => timelines[(row, col)] += above,
+			}
+		}
+	}
+	timelines
+}
+
+impl Render for Grid<Cell> {
+	// beams darken with the number of timelines along them, and splitters no beam reaches are greyed out
+	fn render(&self) -> Scene {
+		const FAINT_BEAM: Color = Color(170, 200, 255);
+		const BRIGHT_BEAM: Color = Color(20, 40, 160);
+
+		let timelines = beam_timelines(self);
+		let most_timelines = timelines.iter().copied().max().unwrap_or(1).max(2) as f64;
+		let mut scene = Scene::new(self.cols() as u64, self.rows() as u64);
+		for ((row, col), &cell) in self.indexed_iter() {
+			let (glyph, color) = match cell {
+				Cell::Start => ('S', Color(30, 160, 60)),
+				Cell::Splitter if row > 0 && timelines[(row - 1, col)] > 0 => ('^', Color(230, 120, 20)),
+				Cell::Splitter => ('^', Color(180, 180, 180)),
+				Cell::Empty if timelines[(row, col)] > 0 => {
+					let brightness = (timelines[(row, col)] as f64).ln() / most_timelines.ln();
+					('|', FAINT_BEAM.blend(BRIGHT_BEAM, brightness))
+				},
+				Cell::Empty => ('.', Color(240, 240, 240)),
+			};
+			scene.push(Shape::Cell { x: col as u64, y: row as u64, glyph, color });
+		}
+		scene
+	}
+}
+
+// scale is both the width and height of the manifold
+fn manifold(rng: &mut Rng, side: usize) -> String {
+	// part 2 counts every timeline, so stop splitting before that count outgrows a u64
+	const MAX_TIMELINES: u128 = 1_000_000_000_000_000;
+
+	let width = side.max(3);
+	let mut rows = vec![];
+	let mut first_row = vec!['.'; width];
+	first_row[width / 2] = 'S';
+	rows.push(first_row);
+
+	let mut timelines = vec![0u128; width];
+	timelines[width / 2] = 1;
+	for row_ix in 1..side.max(2) {
+		let mut row = vec!['.'; width];
+		if row_ix % 2 == 0 {
+			// splitters on the outer columns would send beams off the edge
+			(1..(width - 1)).filter(|_| rng.chance(40)).for_each(|ix| row[ix] = '^');
+
+			let mut split_timelines = vec![0u128; width];
+			for (ix, &count) in timelines.iter().enumerate() {
+				if row[ix] == '^' {
+					split_timelines[ix - 1] += count;
+					split_timelines[ix + 1] += count;
+				} else {
+					split_timelines[ix] += count;
+				}
+			}
+			if split_timelines.iter().sum::<u128>() <= MAX_TIMELINES {
+				timelines = split_timelines;
+			} else {
+				row = vec!['.'; width];
+			}
+		}
+		rows.push(row);
+	}
+
+	rows.iter().map(|row| row.iter().collect::<String>()).join("\n")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 7;
+	const TITLE: &'static str = "Laboratories";
+
+	type Input = Grid<Cell>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(grid: &Self::Input) -> Result<Answer> {
+		let mut row_iter = grid.iter_rows();
+
+		let mut beam_splits: u64 = 0;
+		let first_row = row_iter.by_ref().next().unwrap();
+		let mut active_beams = first_row.enumerate()
+			.filter_map(|(index, &cell)| if cell == Cell::Start { Some(index) } else { None })
+			.collect::<AHashSet<_>>();
+
+		for (row_ix, row) in row_iter.enumerate() {
+			let row = row.collect_vec();
+
+			active_beams = active_beams.iter().flat_map(|&index| {
+				match row[index] {
+					Cell::Empty => vec![index],
+					Cell::Splitter => {
+						beam_splits += 1;
+						[index.checked_sub(1), Some(index + 1)].into_iter().flatten().filter(|&side| side < row.len()).collect()
+					},
+					Cell::Start => unreachable!("Start cells only in first row"),
+				}
+			}).collect();
+			explain!(Detail, "row", row = row_ix + 1, beams = active_beams.len(), splits = beam_splits);
+		}
+
+		Ok(beam_splits.into())
+	}
+
+	fn part2(grid: &Self::Input) -> Result<Answer> {
+		let timelines = beam_timelines(grid);
+		for row in 1..timelines.rows() {
+			explain!(Detail, "row", row = row, timelines = timelines.iter_row(row).sum::<u64>());
+		}
+
+		// every timeline ends somewhere along the bottom row
+		let total = timelines.rows().checked_sub(1).map_or(0, |last_row| timelines.iter_row(last_row).sum::<u64>());
+		Ok(total.into())
+	}
+
+	fn render(grid: &Self::Input) -> Option<Scene> {
+		Some(grid.render())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 141, generate: manifold })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day07::part1(&Day07::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day07::part2(&Day07::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day07::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/07/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(21, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(40, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_render() -> Result<()> {
+		let grid = Day07::parse(TEST)?;
+		assert_eq!(40, beam_timelines(&grid).iter_row(grid.rows() - 1).sum::<u64>());
+
+		let scene = grid.render();
+		let glyphs = scene.shapes.iter().map(|shape| match shape {
+			Shape::Cell { glyph, .. } => *glyph,
+			_ => unreachable!(),
+		}).collect::<String>();
+		let rows = glyphs.as_bytes().chunks(grid.cols()).map(|row| String::from_utf8_lossy(row).into_owned()).collect::<Vec<_>>();
+		assert_eq!(".......S.......", rows[0]);
+		assert_eq!("......|^|......", rows[2]);
+		assert_eq!("|^|^|^|^|^|||^|", rows[14]);
+		Ok(())
+	}
+}
\ No newline at end of file
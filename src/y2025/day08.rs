@@ -0,0 +1,223 @@
+#![allow(unused_imports)]
+
+use std::collections::BTreeMap;
+use ahash::AHashSet;
+use itertools::Itertools;
+use nom::character::complete::{char, line_ending, u64};
+use nom::Parser;
+use nom::combinator::{all_consuming, opt};
+use nom::multi::many1;
+use crate::{both_parts, explain, shared, Answer, Error, Generator, Result, Rng, Solution};
+use crate::cancel::check;
+use crate::parse::{parse_complete, IResult};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Point {
+	x: u64,
+	y: u64,
+	z: u64,
+}
+impl Point {
+	// squared, which orders pairs of boxes just like the distance does but stays exact
+	// each square fits in a u128, only adding all three can overflow
+	fn squared_distance(&self, other: &Point) -> Result<u128> {
+		[(self.x, other.x), (self.y, other.y), (self.z, other.z)].into_iter()
+			.try_fold(0u128, |sum, (a, b)| sum.checked_add(a.abs_diff(b) as u128 * a.abs_diff(b) as u128))
+			.ok_or_else(|| Error::overflow(format!("squared distance between {:?} and {:?}", self, other)))
+	}
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+	(
+		u64,
+		char(','),
+		u64,
+		char(','),
+		u64,
+		opt(line_ending),
+	).map(|(x, _, y, _, z, _)| Point{x, y, z}).parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<Point>> {
+	let res = parse_complete(input, many1(parse_point))?;
+
+	
+	Ok(res)
+}
+
+fn sorted_edges(points: &[Point]) -> Result<Vec<(usize, usize)>> {
+	let mut distances = Vec::with_capacity(points.len() * points.len().saturating_sub(1) / 2);
+	for (a_ix, a) in points.iter().enumerate() {
+		// a box's worth of distances at a time, so a big input can still be stopped before the sort
+		check(|| format!("distances from box #{}", a_ix))?;
+		for (b_ix, b) in points.iter().enumerate().skip(a_ix + 1) {
+			distances.push((a.squared_distance(b)?, (a_ix, b_ix)));
+		}
+	}
+	distances.sort_unstable_by_key(|(dist, _)| *dist);
+	check(|| String::from("sorting wires"))?;
+	Ok(distances.into_iter().map(|(_, edge)| edge).collect())
+}
+
+fn get_root(ix: usize, circuit_ids: &mut Vec<Option<usize>>) -> usize {
+	if let Some(root) = circuit_ids[ix] {
+		let new_root = get_root(root, circuit_ids);
+		circuit_ids[ix] = Some(new_root);
+		new_root
+	} else {
+		ix
+	}
+}
+
+fn part1_internal(points: &[Point], wires_to_connect: usize) -> Result<Answer> {
+	largest_circuits(points, &sorted_edges(points)?, wires_to_connect)
+}
+
+fn largest_circuits(points: &[Point], edges: &[(usize, usize)], wires_to_connect: usize) -> Result<Answer> {
+	let mut circuit_ids = vec![None; points.len()];
+	let mut circuit_sizes = vec![1; points.len()];
+	for (edge_ix, &(a, b)) in edges.iter().take(wires_to_connect).enumerate() {
+		// checking the clock costs more than a union, so only now and then
+		if edge_ix.is_multiple_of(4096) {
+			check(|| format!("wire #{}", edge_ix))?;
+		}
+		// are these points already part of other circuits? get root points
+		let a_root = get_root(a, &mut circuit_ids);
+		let b_root = get_root(b, &mut circuit_ids);
+		let min_root = a_root.min(b_root);
+		let max_root = a_root.max(b_root);
+		// if this has joined two circuits that were previously separate
+		if min_root != max_root {
+			// set the new root and circuit sizes
+			circuit_ids[max_root] = Some(min_root);
+			circuit_sizes[min_root] += circuit_sizes[max_root];
+			circuit_sizes[max_root] = 0;
+			explain!(Detail, "union", a = a, b = b, size = circuit_sizes[min_root]);
+		}
+	}
+	
+	circuit_sizes.sort_unstable();
+	explain!(Summary, "largest circuits", sizes = circuit_sizes.iter().rev().take(3).join(","));
+	Ok(circuit_sizes.into_iter().rev().take(3).product::<usize>().into())
+}
+
+fn last_connection(points: &[Point], edges: &[(usize, usize)]) -> Result<Answer> {
+	let mut circuit_ids = vec![None; points.len()];
+	let mut circuit_sizes = vec![1; points.len()];
+	for (edge_ix, &(a, b)) in edges.iter().enumerate() {
+		if edge_ix.is_multiple_of(4096) {
+			check(|| format!("wire #{}", edge_ix))?;
+		}
+		// are these points already part of other circuits? get root points
+		let a_root = get_root(a, &mut circuit_ids);
+		let b_root = get_root(b, &mut circuit_ids);
+		let min_root = a_root.min(b_root);
+		let max_root = a_root.max(b_root);
+		// if this has joined two circuits that were previously separate
+		if min_root != max_root {
+			// set the new root and circuit sizes
+			circuit_sizes[min_root] += circuit_sizes[max_root];
+			circuit_sizes[max_root] = 0;
+			explain!(Detail, "union", a = a, b = b, size = circuit_sizes[min_root]);
+			// are we done? is the new root 0 and with a circuit size of all points
+			if min_root == 0 && circuit_sizes[min_root] == points.len() {
+				let a_x = points[a].x;
+				let b_x = points[b].x;
+				explain!(Summary, "last connection", a = a, b = b, x = format!("{}*{}", a_x, b_x));
+				return a_x.checked_mul(b_x)
+					.map(Answer::from)
+					.ok_or_else(|| Error::overflow(format!("multiplying x coordinates {} and {}", a_x, b_x)));
+			}
+			circuit_ids[max_root] = Some(min_root);
+		}
+	}
+
+	Err(Error::no_solution("circuit never became fully connected after connecting all nodes to each other?"))
+}
+
+// scale is the number of junction boxes
+fn junction_boxes(rng: &mut Rng, boxes: usize) -> String {
+	let mut seen = AHashSet::new();
+	let mut points = vec![];
+	while points.len() < boxes.max(2) {
+		let point = (rng.range(0..=99_999), rng.range(0..=99_999), rng.range(0..=99_999));
+		if seen.insert(point) {
+			points.push(point);
+		}
+	}
+	points.iter().map(|(x, y, z)| format!("{},{},{}", x, y, z)).join("\n")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 8;
+	const TITLE: &'static str = "Playground";
+
+	type Input = Vec<Point>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(points: &Self::Input) -> Result<Answer> {
+		part1_internal(points, 1000)
+	}
+
+	fn part2(points: &Self::Input) -> Result<Answer> {
+		last_connection(points, &sorted_edges(points)?)
+	}
+
+	// both parts walk the same list of edges, which is by far the most expensive thing to build
+	fn solve_both(points: &Self::Input) -> (Result<Answer>, Result<Answer>) {
+		match shared(|| sorted_edges(points)) {
+			Ok(edges) => both_parts(|| largest_circuits(points, &edges, 1000), || last_connection(points, &edges)),
+			// errors aren't Clone, so part 2 runs into whatever stopped the edges on its own
+			Err(err) => (Err(err), Self::part2(points)),
+		}
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 1000, generate: junction_boxes })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day08::part1(&Day08::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day08::part2(&Day08::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::cancel::{with_cancellation, CancelToken};
+	use crate::y2025::day08::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/08/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(40, part1_internal(&Day08::parse(TEST)?, 10)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(25272, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_cancelled() -> Result<()> {
+		let token = CancelToken::new();
+		token.cancel();
+		let points = Day08::parse(TEST)?;
+		let (part1, part2) = with_cancellation(&token, || Day08::solve_both(&points));
+		assert_eq!("cancelled on distances from box #0", part1.unwrap_err().to_string());
+		assert_eq!("cancelled on distances from box #0", part2.unwrap_err().to_string());
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+#![allow(unused_imports)]
+use geo::{coord, Coord};
+use geo::geometry::{Polygon, Rect};
+use itertools::Itertools;
+use nom::character::complete::{char, line_ending, u64};
+use nom::combinator::{all_consuming, opt};
+use nom::multi::many1;
+use nom::sequence::{separated_pair, terminated};
+use nom::Parser;
+use geo::prelude::*;
+use num::ToPrimitive;
+use ordered_float::NotNan;
+use crate::{explain, Answer, Color, Error, Generator, Render, Result, Rng, Scene, Shape, Solution};
+use crate::parse::{parse_complete, IResult};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Point(u64, u64);
+impl Point {
+	fn area(&self, other: &Point) -> Result<u64> {
+		let Point(self_x, self_y) = self;
+		let Point(other_x, other_y) = other;
+		self_x.abs_diff(*other_x).checked_add(1)
+			.zip(self_y.abs_diff(*other_y).checked_add(1))
+			.and_then(|(width, height)| width.checked_mul(height))
+			.ok_or_else(|| Error::overflow(format!("area of the rectangle between {:?} and {:?}", self, other)))
+	}
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+	terminated(
+		separated_pair(u64, char(','), u64),
+		opt(line_ending)
+	).map(|(a, b)| Point(a, b)).parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<Point>> {
+	let res = parse_complete(input, many1(parse_point))?;
+
+	
+	Ok(res)
+}
+
+fn to_coord(&Point(x, y): &Point) -> Coord {
+	coord! {x: x as f64, y: y as f64}
+}
+
+// opposite corners of the largest rectangle of red tiles that stays inside the loop
+fn largest_covered_rectangle(red_tiles: &[Point]) -> Result<Option<(Point, Point)>> {
+	let red_polygon = Polygon::new(
+		red_tiles.iter().map(to_coord).collect_vec().into(),
+		vec![]
+	);
+
+	let mut max_area = 0;
+	let mut largest = None;
+	for (a, b) in red_tiles.iter().tuple_combinations() {
+		let area = a.area(b)?;
+		if area > max_area && red_polygon.covers(&Rect::new(to_coord(a), to_coord(b))) {
+			max_area = area;
+			largest = Some((*a, *b));
+			explain!(Detail, "larger rectangle", a = format!("{},{}", a.0, a.1), b = format!("{},{}", b.0, b.1), area = area);
+		}
+	}
+	Ok(largest)
+}
+
+impl Render for Vec<Point> {
+	// the loop of red tiles, with the largest rectangle part 2 finds inside it
+	fn render(&self) -> Scene {
+		let width = self.iter().map(|&Point(x, _)| x).max().unwrap_or(0) + 2;
+		let height = self.iter().map(|&Point(_, y)| y).max().unwrap_or(0) + 2;
+		let mut scene = Scene::new(width, height);
+
+		if let Ok(Some((Point(ax, ay), Point(bx, by)))) = largest_covered_rectangle(self) {
+			scene.push(Shape::Rect { from: (ax, ay), to: (bx, by), glyph: 'O', color: Color(90, 140, 230) });
+		}
+		let points = self.iter().map(|&Point(x, y)| (x, y)).collect_vec();
+		scene.push(Shape::Polygon { points, glyph: 'X', color: Color(40, 160, 70) });
+		for &Point(x, y) in self {
+			scene.push(Shape::Cell { x, y, glyph: '#', color: Color(220, 30, 30) });
+		}
+		scene
+	}
+}
+
+// scale is roughly the number of red tiles, which trace the outline of a histogram
+fn red_tiles(rng: &mut Rng, tiles: usize) -> String {
+	const MAX_COORD: u64 = 99_999;
+
+	let columns = (tiles.max(4) - 2) / 2;
+	let gap = (MAX_COORD / 2 / (columns as u64 + 1)).max(1);
+	let base_y = rng.range(1..=1000);
+
+	let mut x = rng.range(1..=gap);
+	let mut xs = vec![x];
+	for _ in 0..columns {
+		x += rng.range(1..=gap);
+		xs.push(x);
+	}
+	let mut heights: Vec<u64> = vec![];
+	for _ in 0..columns {
+		let height = loop {
+			let height = rng.range(1..=(MAX_COORD / 2));
+			if heights.last() != Some(&height) {
+				break height;
+			}
+		};
+		heights.push(height);
+	}
+
+	// up the left side, along the top of every column, then back down and along the bottom
+	let mut corners = vec![(xs[0], base_y)];
+	for (ix, height) in heights.iter().enumerate() {
+		corners.push((xs[ix], base_y + height));
+		corners.push((xs[ix + 1], base_y + height));
+	}
+	corners.push((xs[columns], base_y));
+
+	let start = rng.index(corners.len());
+	corners.rotate_left(start);
+	corners.iter().map(|(x, y)| format!("{},{}", x, y)).join("\n")
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 9;
+	const TITLE: &'static str = "Movie Theater";
+
+	type Input = Vec<Point>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(red_tiles: &Self::Input) -> Result<Answer> {
+		let res = red_tiles.iter().tuple_combinations().map(|(a, b)| a.area(b)).process_results(|areas| areas.max())?
+			.ok_or_else(|| Error::no_solution("a rectangle needs at least two red tiles"))?;
+
+		Ok(res.into())
+	}
+
+	fn part2(red_tiles: &Self::Input) -> Result<Answer> {
+		if red_tiles.len() < 2 {
+			return Err(Error::no_solution("a rectangle needs at least two red tiles"));
+		}
+		let (a, b) = largest_covered_rectangle(red_tiles)?
+			.ok_or_else(|| Error::no_solution("no rectangle between two red tiles stays inside the loop"))?;
+
+		Ok(a.area(&b)?.into())
+	}
+
+	fn render(red_tiles: &Self::Input) -> Option<Scene> {
+		Some(red_tiles.render())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 200, generate: red_tiles })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day09::part1(&Day09::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day09::part2(&Day09::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day09::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/09/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(50, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(24, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_render() -> Result<()> {
+		let scene = Day09::parse(TEST)?.render();
+		assert_eq!((13, 9), (scene.width, scene.height));
+		assert_eq!(Shape::Rect { from: (9, 5), to: (2, 3), glyph: 'O', color: Color(90, 140, 230) }, scene.shapes[0]);
+		Ok(())
+	}
+}
\ No newline at end of file
@@ -0,0 +1,317 @@
+#![allow(unused_imports, unused)]
+
+use std::collections::VecDeque;
+use std::iter::{once, repeat, zip};
+use std::ops::{Add, Mul};
+use ahash::AHashSet;
+use itertools::{chain, Itertools};
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending, u64, usize};
+use nom::combinator::{all_consuming, opt, value};
+use nom::Parser;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, preceded, terminated};
+use pathfinding::prelude::astar;
+use crate::{explain, Answer, Error, Generator, Result, Rng, Solution};
+use crate::cancel::{cancelled, check};
+use crate::parse::{parse_complete, IResult};
+
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct IndicatorLights(AHashSet<usize>);
+impl IndicatorLights {
+	fn apply_button(&self, button: &ButtonSchematic) -> Self {
+		Self(&self.0 ^ &button.0)
+	}
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ButtonSchematic(AHashSet<usize>);
+impl ButtonSchematic {
+	fn to_joltage(&self, joltage_rank: usize) -> JoltageState {
+		let mut joltage = vec![0; joltage_rank];
+		self.0.iter().for_each(|&ix| {
+			joltage[ix] += 1;
+		});
+		JoltageState(joltage)
+	}
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct JoltageState(Vec<usize>);
+impl JoltageState {
+	fn with_size(size: usize) -> Self {
+		Self(vec![0; size])
+	}
+	
+	const fn rank(&self) -> usize {
+		self.0.len()
+	}
+	
+	fn apply_button(&self, button: &ButtonSchematic) -> Self {
+		let mut new_joltage = self.clone();
+		button.0.iter().for_each(|&ix| {
+			new_joltage.0[ix] += 1;
+		});
+		new_joltage
+	}
+}
+impl Add for JoltageState {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		Self(self.0.iter().enumerate().map(|(ix, &joltage)| {
+			joltage + rhs.0[ix]
+		}).collect_vec())
+	}
+}
+impl Mul<usize> for JoltageState {
+	type Output = Self;
+
+	fn mul(self, rhs: usize) -> Self::Output {
+		Self(self.0.iter().map(|&joltage| {
+			joltage * rhs
+		}).collect_vec())
+	}
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Machine(IndicatorLights, Vec<ButtonSchematic>, JoltageState);
+
+fn parse_indicator_lights(input: &str) -> IResult<&str, IndicatorLights> {
+	delimited(
+		char('['),
+		many1(alt((
+			value(false, char('.')),
+			value(true, char('#'))
+		))),
+		char(']')
+	).map(|lights|
+		IndicatorLights(lights.into_iter().enumerate().filter_map(|(ix, state)| 
+			if state { Some(ix) } else { None }
+		).collect())
+	).parse(input)
+}
+
+fn parse_button_schematic(input: &str) -> IResult<&str, ButtonSchematic> {
+	delimited(
+		char('('),
+		separated_list1(char(','), usize),
+		char(')')
+	).map(|wires| ButtonSchematic(AHashSet::from_iter(wires))).parse(input)
+}
+
+fn parse_joltage(input: &str) -> IResult<&str, JoltageState> {
+	delimited(
+		char('{'),
+		separated_list1(char(','), usize),
+		char('}')
+	).map(JoltageState).parse(input)
+}
+
+fn parse_machine(input: &str) -> IResult<&str, Machine> {
+	(
+		parse_indicator_lights,
+		many1(preceded(
+			char(' '),
+			parse_button_schematic
+		)),
+		delimited(
+			char(' '),
+			parse_joltage,
+			opt(line_ending)
+		)
+	).map(|(indicators, buttons, joltage)|
+		Machine(indicators, buttons, joltage)
+	).parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>> {
+	let res = parse_complete(input, many1(parse_machine))?;
+
+	
+	Ok(res)
+}
+
+// scale is the number of machines, kept small enough that every one is solvable quickly
+fn machines(rng: &mut Rng, machines: usize) -> String {
+	(0..machines.max(1)).map(|_| {
+		let lights = rng.range(3..=6) as usize;
+		let buttons = (0..rng.range(2..=6)).map(|_| {
+			let wires = (0..lights).filter(|_| rng.chance(40)).collect_vec();
+			if wires.is_empty() { vec![rng.index(lights)] } else { wires }
+		}).collect_vec();
+
+		// work backwards from a set of presses, so there is always a way to reach the target
+		let mut target_lights = AHashSet::new();
+		while target_lights.is_empty() {
+			for button in buttons.iter().filter(|_| rng.chance(50)) {
+				target_lights = &target_lights ^ &button.iter().copied().collect();
+			}
+		}
+		let mut joltage = vec![0; lights];
+		for button in &buttons {
+			let presses = rng.range(0..=4) as usize;
+			button.iter().for_each(|&wire| joltage[wire] += presses);
+		}
+
+		format!(
+			"[{}] {} {{{}}}",
+			(0..lights).map(|ix| if target_lights.contains(&ix) { '#' } else { '.' }).collect::<String>(),
+			buttons.iter().map(|wires| format!("({})", wires.iter().join(","))).join(" "),
+			joltage.iter().join(",")
+		)
+	}).join("\n")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 10;
+	const TITLE: &'static str = "Factory";
+
+	type Input = Vec<Machine>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(machines: &Self::Input) -> Result<Answer> {
+		let button_presses = machines.iter().enumerate().map(|(machine_ix, Machine(target_lights, buttons, _))| {
+			let mut search_nodes = VecDeque::from([(IndicatorLights::default(), AHashSet::<usize>::with_capacity(buttons.len()))]);
+			let mut searched = 0usize;
+			while let Some((current_lights, pressed_buttons)) = search_nodes.pop_front() {
+				// checking the clock costs more than a node, so only now and then
+				if searched.is_multiple_of(4096) {
+					check(|| format!("machine #{}", machine_ix))?;
+				}
+				searched += 1;
+				for (button_ix, unpressed_button) in buttons.iter().enumerate().filter(|(ix, _)| !pressed_buttons.contains(ix)) {
+					let new_lights = current_lights.apply_button(unpressed_button);
+					if new_lights == *target_lights {
+						explain!(Summary, "machine", machine = machine_ix, presses = pressed_buttons.len() + 1);
+						return Ok(pressed_buttons.len() as u64 + 1);
+					}
+					let mut new_buttons = pressed_buttons.clone();
+					new_buttons.insert(button_ix);
+					search_nodes.push_back((new_lights, new_buttons))
+				}
+			}
+			Err(Error::no_solution(format!("machine #{} never matched its indicator lights after pressing all buttons", machine_ix)))
+		}).sum::<Result<u64>>()?;
+
+		Ok(button_presses.into())
+	}
+
+	fn part2(machines: &Self::Input) -> Result<Answer> {
+		// fn generate_counting_iter_fn(max_val: usize, digits_left: usize) -> Box<dyn Iterator<Item = Vec<usize>>> {
+		// 	if digits_left == 1 {
+		// 		Box::new(once(vec![max_val]))
+		// 	} else {
+		// 		Box::new((0..=max_val).flat_map(move |curr_val| {
+		// 			let head = once(curr_val);
+		// 			generate_counting_iter_fn(max_val - curr_val, digits_left - 1).into_iter()
+		// 				.map(move |tail| chain(head.clone(), tail).collect())
+		// 		}))
+		// 	}
+		// }
+
+		let min_steps = machines.iter().enumerate().map(|(machine_ix, Machine(_, buttons, target_joltage))| {
+			// each joltage can only be incremented by a max of 1 per button press
+			// the least number of button presses possible is equal to the highest joltage reading
+			// let &min_button_presses = target_joltage.0.iter().max().unwrap();
+			// let num_buttons = buttons.len();
+			// let buttons_pressed = generate_counting_iter_fn(min_button_presses, num_buttons);
+
+			let joltage = |node: &[usize]| node.iter().enumerate().map(|(ix, &button_presses)| {
+				buttons[ix].to_joltage(target_joltage.rank()) * button_presses
+			}).reduce(|acc, j| acc + j).unwrap();
+
+			let path_and_steps = astar(
+				&vec![0; buttons.len()],
+				|node| {
+					let target_node = target_joltage.clone();
+					let state = joltage(node);
+
+					let curr_node = node.clone();
+					buttons.iter().enumerate().filter_map(move |(ix, button)| {
+						let mut new_node = curr_node.clone();
+						new_node[ix] += 1;
+						let new_state = state.apply_button(button);
+
+						if new_state.0.iter().enumerate()
+							.any(|(ix, &node_joltage)| node_joltage > target_node.0[ix]) {
+							None
+						} else {
+							Some((new_node, 1))
+						}
+					})
+				},
+				|node| {
+					let state = joltage(node);
+					target_joltage.0.iter().enumerate().map(|(ix, &target_j)| {
+						target_j.abs_diff(state.0[ix])
+					}).sum()
+				},
+				// a cancelled search stops at whichever node it's on, which is told apart from the target below
+				|node| cancelled() || joltage(node) == *target_joltage
+			);
+			let (path, steps) = match path_and_steps {
+				Some((path, steps)) if joltage(path.last().unwrap()) == *target_joltage => (path, steps),
+				_ => {
+					check(|| format!("machine #{}", machine_ix))?;
+					return Err(Error::no_solution(format!("machine #{} can never reach its target joltage", machine_ix)));
+				},
+			};
+
+			explain!(Summary, "machine", machine = machine_ix, presses = steps, per_button = path.last().unwrap().iter().join(","));
+			Ok(steps as u64)
+		}).sum::<Result<u64>>()?;
+
+		Ok(min_steps.into())
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 160, generate: machines })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day10::part1(&Day10::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day10::part2(&Day10::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+	use crate::cancel::{with_cancellation, CancelToken};
+	use crate::y2025::day10::*;
+
+	const TEST: &str = include_str!("../../tests/examples/2025/10/example.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(7, part1(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(33, part2(TEST)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_cancelled() {
+		let token = CancelToken::new();
+		token.cancel();
+		let err = with_cancellation(&token, || part2(TEST)).unwrap_err();
+		assert_eq!("cancelled on machine #0", err.to_string());
+
+		let err = with_cancellation(&CancelToken::with_timeout(Duration::ZERO), || part1(TEST)).unwrap_err();
+		assert!(matches!(err, Error::TimedOut { location, .. } if location == "machine #0"));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,318 @@
+#![allow(unused_imports)]
+
+use ahash::{AHashMap, AHashSet};
+use itertools::Itertools;
+use nom::bytes::tag;
+use nom::character::complete::{alpha1, line_ending, space1};
+use nom::combinator::{all_consuming, map, opt};
+use nom::error::context;
+use nom::multi::{many1, separated_list1};
+use nom::Parser;
+use nom::sequence::{separated_pair, terminated};
+use pathfinding::prelude::count_paths;
+use crate::{both_parts, explain, shared, Answer, Error, Generator, Result, Rng, Solution};
+use crate::parse::{parse_complete, IResult, ParseDiagnostic};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Device(String);
+
+#[derive(Debug, Clone)]
+pub struct OutputList(Device, Vec<Device>);
+
+fn parse_device(input: &str) -> IResult<&str, Device> {
+	map(alpha1, |label| Device(String::from(label))).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, OutputList> {
+	map(
+		terminated(
+			separated_pair(
+				parse_device, 
+				context("': '", tag(": ")), 
+				separated_list1(space1, parse_device)
+			),
+			opt(line_ending)
+		), 
+		|(device, outputs)| OutputList(device, outputs)
+	).parse(input)
+}
+
+fn parse(input: &str) -> Result<Vec<OutputList>> {
+	let res = parse_complete(input, many1(parse_line))?;
+
+	// devices are numbered by the line listing their outputs, so each may only be listed once, and "out" never
+	let mut listed = AHashSet::from([Device(String::from("out"))]);
+	if let Some(line_ix) = res.iter().position(|OutputList(device, _)| !listed.insert(device.clone())) {
+		return Err(Error::Parse(ParseDiagnostic::at(input, line_ix, 0, "a device not already listed")));
+	}
+	Ok(res)
+}
+
+fn find_device<'a>(reverse_index_mapping: &'a AHashMap<Device, usize>, label: &str) -> Result<&'a usize> {
+	reverse_index_mapping.get(&Device(String::from(label)))
+		.ok_or_else(|| Error::no_solution(format!("no device labelled '{}'", label)))
+}
+
+// every device numbered by its position in the list, with the output device last
+struct DeviceGraph {
+	reverse_index_mapping: AHashMap<Device, usize>,
+	device_output_map: Vec<Vec<usize>>,
+}
+
+fn build_graph(device_output_list: &[OutputList]) -> Result<DeviceGraph> {
+	const TARGET_NODE: &str = "out";
+
+	let device_output_index = device_output_list.iter().map(
+		|OutputList(device, _)| device.clone()
+	).chain(vec![Device(String::from(TARGET_NODE))]).collect_vec();
+
+	let reverse_index_mapping = AHashMap::from_iter(device_output_index
+		.iter().enumerate().map(|(ix, device)| {(device.clone(), ix)}));
+
+	let device_output_map = device_output_list.iter().cloned()
+		.chain(vec![OutputList(Device(String::from(TARGET_NODE)), vec![])])
+		.map(|OutputList(device, mapping)| {
+			let &device_ix = find_device(&reverse_index_mapping, &device.0)?;
+			let output_ix_list = mapping.into_iter().map(|Device(output_label)|
+				find_device(&reverse_index_mapping, &output_label).copied()
+			).collect::<Result<Vec<_>>>()?;
+
+			Ok((device_ix, output_ix_list))
+		})
+		.collect::<Result<Vec<_>>>()?
+		.into_iter()
+		.sorted_unstable_by_key(|&(ix, _)| ix)
+		.map(|(_, output_list)| output_list)
+		.collect_vec();
+
+	// paths are only countable without loops, so repeatedly take away devices nothing leads to any more
+	// whatever is left over afterwards is on a loop, or only reachable from one
+	let mut inputs = vec![0; device_output_map.len()];
+	device_output_map.iter().flatten().for_each(|&output_ix| inputs[output_ix] += 1);
+	let mut unlinked = (0..inputs.len()).filter(|&ix| inputs[ix] == 0).collect_vec();
+	while let Some(ix) = unlinked.pop() {
+		for &output_ix in &device_output_map[ix] {
+			inputs[output_ix] -= 1;
+			if inputs[output_ix] == 0 {
+				unlinked.push(output_ix);
+			}
+		}
+	}
+	if let Some(looped_ix) = inputs.iter().position(|&count| count > 0) {
+		return Err(Error::no_solution(format!("device '{}' is on a loop or fed by one, so there's no end to its paths", device_output_index[looped_ix].0)));
+	}
+
+	Ok(DeviceGraph { reverse_index_mapping, device_output_map })
+}
+
+fn count_paths_from_you(graph: &DeviceGraph) -> Result<Answer> {
+	const START_NODE: &str = "you";
+	const TARGET_NODE: &str = "out";
+
+	let DeviceGraph { reverse_index_mapping, device_output_map } = graph;
+	let start_index = find_device(reverse_index_mapping, START_NODE)?;
+	let target_index = find_device(reverse_index_mapping, TARGET_NODE)?;
+
+	let num_paths = count_paths(
+		start_index,
+		|&curr_index| device_output_map[*curr_index].iter(),
+		|&curr_index| curr_index == target_index
+	);
+	explain!(Summary, "paths", from = START_NODE, to = TARGET_NODE, count = num_paths);
+
+	Ok(num_paths.into())
+}
+
+fn count_paths_through_dac_and_fft(graph: &DeviceGraph) -> Result<Answer> {
+	const START_NODE: &str = "svr";
+	const DAC_NODE: &str = "dac";
+	const FFT_NODE: &str = "fft";
+	const TARGET_NODE: &str = "out";
+
+	let DeviceGraph { reverse_index_mapping, device_output_map } = graph;
+	let start_index = find_device(reverse_index_mapping, START_NODE)?;
+	let dac_index = find_device(reverse_index_mapping, DAC_NODE)?;
+	let fft_index = find_device(reverse_index_mapping, FFT_NODE)?;
+	let target_index = find_device(reverse_index_mapping, TARGET_NODE)?;
+
+	// want num of paths from start to target including both dac and fft (in any order)
+	// count paths for subsets of the total desired path, then combine as appropriate
+
+	// count(start -> dac/fft -> fft/dac -> target)
+	// = count(start -> dac -> fft -> target) + count(start -> fft -> dac -> target)
+
+	// count(start -> dac -> fft -> target)
+	// = count(start -> dac) * count(dac -> fft) * count(fft -> target)
+
+	let successors = |&curr_index: &&usize| device_output_map[*curr_index].iter();
+	let equals = |target_index: &usize| {
+		let target_index = *target_index;
+		move |&&curr_index: &&usize| curr_index == target_index
+	};
+
+	let start_dac_paths = count_paths(start_index, successors, equals(dac_index));
+	let dac_fft_paths = count_paths(dac_index, successors, equals(fft_index));
+	let fft_target_paths = count_paths(fft_index, successors, equals(target_index));
+	explain!(Summary, "paths", from = START_NODE, to = DAC_NODE, count = start_dac_paths);
+	explain!(Summary, "paths", from = DAC_NODE, to = FFT_NODE, count = dac_fft_paths);
+	explain!(Summary, "paths", from = FFT_NODE, to = TARGET_NODE, count = fft_target_paths);
+	let start_dac_fft_paths = start_dac_paths.checked_mul(dac_fft_paths)
+		.and_then(|paths| paths.checked_mul(fft_target_paths))
+		.ok_or_else(|| Error::overflow("counting paths through dac then fft"))?;
+
+	let start_fft_paths = count_paths(start_index, successors, equals(fft_index));
+	let fft_dac_paths = count_paths(fft_index, successors, equals(dac_index));
+	let dac_target_paths = count_paths(dac_index, successors, equals(target_index));
+	explain!(Summary, "paths", from = START_NODE, to = FFT_NODE, count = start_fft_paths);
+	explain!(Summary, "paths", from = FFT_NODE, to = DAC_NODE, count = fft_dac_paths);
+	explain!(Summary, "paths", from = DAC_NODE, to = TARGET_NODE, count = dac_target_paths);
+	let start_fft_dac_paths = start_fft_paths.checked_mul(fft_dac_paths)
+		.and_then(|paths| paths.checked_mul(dac_target_paths))
+		.ok_or_else(|| Error::overflow("counting paths through fft then dac"))?;
+
+	let total_paths = start_dac_fft_paths.checked_add(start_fft_dac_paths)
+		.ok_or_else(|| Error::overflow("summing paths through dac and fft"))?;
+
+	Ok(total_paths.into())
+}
+
+// scale is the number of devices, wired into a DAG that always leads from you and svr to out
+fn device_graph(rng: &mut Rng, devices: usize) -> String {
+	// path counts are multiplied together in part 2, so keep each one small
+	const MAX_PATHS: u64 = 1000;
+	const RESERVED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+	let devices = devices.max(5);
+	let label_len = if devices < 5000 { 3 } else { 4 };
+	let mut labels = vec![String::new(); devices];
+	let mut seen = RESERVED.iter().map(|label| label.to_string()).collect::<AHashSet<_>>();
+	for label in labels.iter_mut() {
+		*label = loop {
+			let label = (0..label_len).map(|_| char::from(b'a' + rng.range(0..=25) as u8)).collect::<String>();
+			if seen.insert(label.clone()) {
+				break label;
+			}
+		};
+	}
+
+	// devices are wired in this order, so svr comes first and every device can reach the last one
+	let mut special = (1..devices).collect_vec();
+	rng.shuffle(&mut special);
+	labels[0] = String::from("svr");
+	for (&ix, label) in special.iter().zip(["you", "fft", "dac"]) {
+		labels[ix] = String::from(label);
+	}
+	let sources = [0, special[0], special[1], special[2]];
+
+	let mut outputs = vec![vec![]; devices];
+	let mut paths = vec![vec![0u64; devices]; sources.len()];
+	for (source_ix, &source) in sources.iter().enumerate() {
+		paths[source_ix][source] = 1;
+	}
+	for device in 1..devices {
+		let mut inputs = vec![device - 1];
+		for _ in 0..rng.range(0..=2) {
+			let input = rng.index(device);
+			if !inputs.contains(&input) {
+				inputs.push(input);
+			}
+		}
+
+		let count_paths = |inputs: &[usize], paths: &[Vec<u64>]| paths.iter().enumerate()
+			.map(|(source_ix, counts)| {
+				let own = u64::from(sources[source_ix] == device);
+				own + inputs.iter().map(|&input| counts[input]).sum::<u64>()
+			})
+			.collect_vec();
+		let mut counts = count_paths(&inputs, &paths);
+		if counts.iter().any(|&count| count > MAX_PATHS) {
+			inputs.truncate(1);
+			counts = count_paths(&inputs, &paths);
+		}
+
+		for (source_ix, count) in counts.into_iter().enumerate() {
+			paths[source_ix][device] = count;
+		}
+		inputs.into_iter().for_each(|input| outputs[input].push(device));
+	}
+
+	let mut lines = outputs.iter().enumerate().map(|(device, outputs)| {
+		let mut outputs = outputs.iter().map(|&output| labels[output].as_str()).collect_vec();
+		if outputs.is_empty() || rng.chance(10) {
+			outputs.push("out");
+		}
+		format!("{}: {}", labels[device], outputs.join(" "))
+	}).collect_vec();
+	rng.shuffle(&mut lines);
+	lines.join("\n")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+	const YEAR: u16 = 2025;
+	const DAY: u8 = 11;
+	const TITLE: &'static str = "Reactor";
+
+	type Input = Vec<OutputList>;
+
+	fn parse(input: &str) -> Result<Self::Input> {
+		parse(input)
+	}
+
+	fn part1(device_output_list: &Self::Input) -> Result<Answer> {
+		count_paths_from_you(&build_graph(device_output_list)?)
+	}
+
+	fn part2(device_output_list: &Self::Input) -> Result<Answer> {
+		count_paths_through_dac_and_fft(&build_graph(device_output_list)?)
+	}
+
+	fn solve_both(device_output_list: &Self::Input) -> (Result<Answer>, Result<Answer>) {
+		match shared(|| build_graph(device_output_list)) {
+			Ok(graph) => both_parts(|| count_paths_from_you(&graph), || count_paths_through_dac_and_fft(&graph)),
+			// errors aren't Clone, so build the graph again to hand each part its own
+			Err(err) => (Err(err), Self::part2(device_output_list)),
+		}
+	}
+
+	fn generator() -> Option<Generator> {
+		Some(Generator { default_scale: 600, generate: device_graph })
+	}
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+	Day11::part1(&Day11::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+	Day11::part2(&Day11::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::y2025::day11::*;
+
+	const TEST1: &str = include_str!("../../tests/examples/2025/11/example1.txt");
+	const TEST2: &str = include_str!("../../tests/examples/2025/11/example2.txt");
+
+	#[test]
+	fn test_part_one() -> Result<()> {
+		assert_eq!(5, part1(TEST1)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_part_two() -> Result<()> {
+		assert_eq!(2, part2(TEST2)?);
+		Ok(())
+	}
+
+	#[test]
+	fn test_solve_both() -> Result<()> {
+		let (part1, part2) = Day11::solve_both(&Day11::parse(TEST2)?);
+		assert!(matches!(part1, Err(Error::NoSolution(_))));
+		assert_eq!(2, part2?);
+		Ok(())
+	}
+}
\ No newline at end of file
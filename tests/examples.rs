@@ -0,0 +1,92 @@
+use std::env;
+use std::panic;
+use std::process::ExitCode;
+use aoc25::{discover_examples, examples_dir, get_day, read_input, Example, InputSource, Result, Verdict, YEARS};
+
+// every example part is its own case, named like 2025/11/example2 part 2, so a failure says which one
+// this runs without the libtest harness, since the cases are only known once the examples directory has been read
+struct Case {
+	name: String,
+	run: Box<dyn FnOnce() -> Result<(), String>>,
+}
+
+fn example_part(example: &Example, part: u8) -> Result<(), String> {
+	let solution = get_day(example.year, example.day).map_err(|err| err.to_string())?;
+	let input = read_input(example.year, example.day, &InputSource::File(example.input.clone())).map_err(|err| err.to_string())?;
+	let answer = match part {
+		1 => solution.part1(&input),
+		_ => solution.part2(&input),
+	}.map_err(|err| err.to_string())?;
+
+	match example.answers.check(example.day, part, &answer) {
+		Verdict::Correct => Ok(()),
+		Verdict::Incorrect { expected } => Err(format!("expected {}, got {}", expected, answer)),
+		Verdict::Unknown => Err(String::from("no expected answer")),
+	}
+}
+
+fn every_day_has_examples(examples: &[Example]) -> Result<(), String> {
+	match YEARS.iter().flat_map(|year| year.days).find(|solution| !examples.iter().any(|example| (example.year, example.day) == (solution.year(), solution.day()))) {
+		Some(solution) => Err(format!("no examples for day {:0>2} of {}", solution.day(), solution.year())),
+		None => Ok(()),
+	}
+}
+
+fn cases() -> Result<Vec<Case>> {
+	let examples = discover_examples(&examples_dir())?;
+
+	let mut cases = vec![];
+	for example in &examples {
+		for part in [1, 2] {
+			if example.answers.get(example.day, part).is_none() {
+				continue;
+			}
+			let example = example.clone();
+			cases.push(Case {
+				name: format!("{}/{:0>2}/{} part {}", example.year, example.day, example.name, part),
+				run: Box::new(move || example_part(&example, part)),
+			});
+		}
+	}
+	cases.push(Case { name: String::from("every day has examples"), run: Box::new(move || every_day_has_examples(&examples)) });
+	Ok(cases)
+}
+
+fn main() -> ExitCode {
+	let cases = match cases() {
+		Ok(cases) => cases,
+		Err(err) => {
+			eprintln!("could not read the examples in {}: {}", examples_dir().display(), err);
+			return ExitCode::FAILURE;
+		},
+	};
+
+	// like libtest, the first argument that isn't a flag picks out the cases whose names contain it
+	let filter = env::args().skip(1).find(|arg| !arg.starts_with('-')).unwrap_or_default();
+	let cases = cases.into_iter().filter(|case| case.name.contains(&filter)).collect::<Vec<_>>();
+
+	println!();
+	println!("running {} examples", cases.len());
+	let mut failures = vec![];
+	for Case { name, run } in cases {
+		let res = panic::catch_unwind(panic::AssertUnwindSafe(run)).unwrap_or_else(|_| Err(String::from("panicked")));
+		match res {
+			Ok(()) => println!("example {} ... ok", name),
+			Err(err) => {
+				println!("example {} ... FAILED", name);
+				failures.push(format!("{}: {}", name, err));
+			},
+		}
+	}
+
+	println!();
+	if !failures.is_empty() {
+		println!("failures:");
+		failures.iter().for_each(|failure| println!("    {}", failure));
+		println!();
+		println!("example result: FAILED. {} failed", failures.len());
+		return ExitCode::FAILURE;
+	}
+	println!("example result: ok");
+	ExitCode::SUCCESS
+}
\ No newline at end of file
@@ -0,0 +1,520 @@
+use std::iter::repeat;
+use ahash::AHashMap;
+use ahash::AHashSet;
+use itertools::Itertools;
+use aoc25::{discover_examples, examples_dir, generate_input, get_day, get_year, read_input, Answer, InputSource};
+
+// deliberately simple solvers that parse their own input, kept obviously correct rather than fast
+// they only ever see generated inputs and examples, so they panic on anything they can't read
+type Reference = fn(&str) -> u64;
+
+// each day's reference parts, with a scale small enough that they finish quickly
+const REFERENCES: &[(u8, usize, Reference, Reference)] = &[
+	(1, 300, day01_part1, day01_part2),
+	(2, 5, day02_part1, day02_part2),
+	(3, 20, day03_part1, day03_part2),
+	(4, 20, day04_part1, day04_part2),
+	(5, 30, day05_part1, day05_part2),
+	(6, 30, day06_part1, day06_part2),
+	(7, 30, day07_part1, day07_part2),
+	(8, 200, day08_part1, day08_part2),
+	(9, 16, day09_part1, day09_part2),
+	(10, 10, day10_part1, day10_part2),
+	(11, 30, day11_part1, day11_part2),
+];
+
+const SEEDS: u64 = 10;
+
+// every reference here is for a 2025 puzzle
+const YEAR: u16 = 2025;
+
+// turns the dial a click at a time, counting zeros either after every click or only where rotations stop
+fn day01_zeros(input: &str, every_click: bool) -> u64 {
+	let mut dial: i64 = 50;
+	let mut zeros = 0;
+	for line in input.lines() {
+		let (direction, clicks) = line.split_at(1);
+		let step = if direction == "L" { -1 } else { 1 };
+		let clicks = clicks.parse::<u64>().unwrap();
+		for click in 1..=clicks {
+			dial = (dial + step).rem_euclid(100);
+			if dial == 0 && (every_click || click == clicks) {
+				zeros += 1;
+			}
+		}
+	}
+	zeros
+}
+
+fn day01_part1(input: &str) -> u64 {
+	day01_zeros(input, false)
+}
+
+fn day01_part2(input: &str) -> u64 {
+	day01_zeros(input, true)
+}
+
+// every id in every range, one at a time
+fn day02_ids(input: &str) -> impl Iterator<Item = String> + '_ {
+	input.trim().split(',').flat_map(|range| {
+		let (start, end) = range.split_once('-').unwrap();
+		(start.parse::<u64>().unwrap()..=end.parse().unwrap()).map(|id| id.to_string())
+	})
+}
+
+fn is_repeated(digits: &str, times: usize) -> bool {
+	digits.len().is_multiple_of(times) && digits == digits[..(digits.len() / times)].repeat(times)
+}
+
+fn day02_part1(input: &str) -> u64 {
+	day02_ids(input).filter(|id| is_repeated(id, 2)).map(|id| id.parse::<u64>().unwrap()).sum()
+}
+
+fn day02_part2(input: &str) -> u64 {
+	day02_ids(input)
+		.filter(|id| (2..=id.len()).any(|times| is_repeated(id, times)))
+		.map(|id| id.parse::<u64>().unwrap())
+		.sum()
+}
+
+fn day03_banks(input: &str) -> Vec<Vec<u64>> {
+	input.lines().map(|line| line.bytes().map(|digit| u64::from(digit - b'0')).collect()).collect()
+}
+
+fn day03_part1(input: &str) -> u64 {
+	day03_banks(input).iter()
+		.map(|bank| bank.iter().tuple_combinations().map(|(a, b)| a * 10 + b).max().unwrap())
+		.sum()
+}
+
+// tries taking and skipping every battery, remembering the best for each position and count left
+fn largest_joltage(bank: &[u64], from: usize, count: u32, seen: &mut AHashMap<(usize, u32), Option<u64>>) -> Option<u64> {
+	if count == 0 {
+		return Some(0);
+	}
+	if bank.len() - from < count as usize {
+		return None;
+	}
+	if let Some(&best) = seen.get(&(from, count)) {
+		return best;
+	}
+
+	let take = largest_joltage(bank, from + 1, count - 1, seen).map(|rest| bank[from] * 10u64.pow(count - 1) + rest);
+	let skip = largest_joltage(bank, from + 1, count, seen);
+	let best = take.max(skip);
+	seen.insert((from, count), best);
+	best
+}
+
+fn day03_part2(input: &str) -> u64 {
+	day03_banks(input).iter()
+		.map(|bank| largest_joltage(bank, 0, 12, &mut AHashMap::new()).unwrap())
+		.sum()
+}
+
+fn day04_rolls(input: &str) -> AHashSet<(i64, i64)> {
+	input.lines().enumerate()
+		.flat_map(|(row, line)| line.char_indices().filter(|&(_, cell)| cell == '@').map(move |(col, _)| (row as i64, col as i64)))
+		.collect()
+}
+
+fn accessible_rolls(rolls: &AHashSet<(i64, i64)>) -> Vec<(i64, i64)> {
+	rolls.iter()
+		.filter(|&&(row, col)| {
+			let neighbours = (-1..=1).cartesian_product(-1..=1)
+				.filter(|&offset| offset != (0, 0) && rolls.contains(&(row + offset.0, col + offset.1)))
+				.count();
+			neighbours < 4
+		})
+		.copied()
+		.collect()
+}
+
+fn day04_part1(input: &str) -> u64 {
+	accessible_rolls(&day04_rolls(input)).len() as u64
+}
+
+// removes every accessible roll at once, round after round, until none are left to remove
+fn day04_part2(input: &str) -> u64 {
+	let mut rolls = day04_rolls(input);
+	let mut removed = 0;
+	loop {
+		let accessible = accessible_rolls(&rolls);
+		if accessible.is_empty() {
+			return removed;
+		}
+		removed += accessible.len() as u64;
+		accessible.iter().for_each(|roll| {
+			rolls.remove(roll);
+		});
+	}
+}
+
+fn day05_database(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
+	let (ranges, ingredients) = input.split_once("\n\n").unwrap();
+	let ranges = ranges.lines().map(|line| {
+		let (start, end) = line.split_once('-').unwrap();
+		(start.parse().unwrap(), end.parse().unwrap())
+	}).collect();
+	let ingredients = ingredients.lines().map(|line| line.parse().unwrap()).collect();
+	(ranges, ingredients)
+}
+
+fn day05_part1(input: &str) -> u64 {
+	let (ranges, ingredients) = day05_database(input);
+	ingredients.iter().filter(|&&id| ranges.iter().any(|&(start, end)| (start..=end).contains(&id))).count() as u64
+}
+
+// sorts the ranges and merges each into the last one it overlaps
+fn day05_part2(input: &str) -> u64 {
+	let (mut ranges, _) = day05_database(input);
+	ranges.sort_unstable();
+
+	let mut merged: Vec<(u64, u64)> = vec![];
+	for (start, end) in ranges {
+		match merged.last_mut() {
+			Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+			_ => merged.push((start, end)),
+		}
+	}
+	merged.iter().map(|(start, end)| end - start + 1).sum()
+}
+
+// cuts the worksheet into problems at the columns that are blank in every row of numbers
+fn day06_problems(input: &str) -> Vec<(char, Vec<String>)> {
+	let lines = input.lines().map(|line| line.chars().collect_vec()).collect_vec();
+	let (operations, numbers) = lines.split_last().unwrap();
+	let width = lines.iter().map(Vec::len).max().unwrap();
+	let cell = |row: &Vec<char>, col: usize| row.get(col).copied().unwrap_or(' ');
+
+	let blank = (0..width).map(|col| numbers.iter().all(|row| cell(row, col) == ' ')).collect_vec();
+	let mut problems = vec![];
+	let mut col = 0;
+	while col < width {
+		if blank[col] {
+			col += 1;
+			continue;
+		}
+		let start = col;
+		while col < width && !blank[col] {
+			col += 1;
+		}
+		let rows = numbers.iter().map(|row| (start..col).map(|col| cell(row, col)).collect()).collect();
+		problems.push((cell(operations, start), rows));
+	}
+	problems
+}
+
+fn day06_solve(op: char, numbers: impl Iterator<Item = String>) -> u64 {
+	let numbers = numbers.map(|number| number.trim().parse::<u64>().unwrap());
+	match op {
+		'+' => numbers.sum(),
+		_ => numbers.product(),
+	}
+}
+
+fn day06_part1(input: &str) -> u64 {
+	day06_problems(input).into_iter().map(|(op, rows)| day06_solve(op, rows.into_iter())).sum()
+}
+
+// each column of a problem, read top to bottom, is one number
+fn day06_part2(input: &str) -> u64 {
+	day06_problems(input).into_iter()
+		.map(|(op, rows)| {
+			let width = rows[0].len();
+			let columns = (0..width).map(|col| rows.iter().map(|row| row.chars().nth(col).unwrap()).collect::<String>());
+			day06_solve(op, columns)
+		})
+		.sum()
+}
+
+fn day07_manifold(input: &str) -> Vec<Vec<char>> {
+	input.lines().map(|line| line.chars().collect()).collect()
+}
+
+// follows every beam down from the start, noting each splitter any of them reaches
+fn day07_part1(input: &str) -> u64 {
+	let manifold = day07_manifold(input);
+	let start = manifold[0].iter().position(|&cell| cell == 'S').unwrap();
+
+	let mut beams = vec![(0, start)];
+	let mut seen = AHashSet::new();
+	let mut splitters = AHashSet::new();
+	while let Some((row, col)) = beams.pop() {
+		if row + 1 == manifold.len() || !seen.insert((row, col)) {
+			continue;
+		}
+		if manifold[row + 1][col] == '^' {
+			splitters.insert((row + 1, col));
+			beams.extend([(row + 1, col - 1), (row + 1, col + 1)]);
+		} else {
+			beams.push((row + 1, col));
+		}
+	}
+	splitters.len() as u64
+}
+
+fn day07_timelines(manifold: &[Vec<char>], row: usize, col: usize, seen: &mut AHashMap<(usize, usize), u64>) -> u64 {
+	if row + 1 == manifold.len() {
+		return 1;
+	}
+	if let Some(&timelines) = seen.get(&(row, col)) {
+		return timelines;
+	}
+
+	let timelines = if manifold[row + 1][col] == '^' {
+		day07_timelines(manifold, row + 1, col - 1, seen) + day07_timelines(manifold, row + 1, col + 1, seen)
+	} else {
+		day07_timelines(manifold, row + 1, col, seen)
+	};
+	seen.insert((row, col), timelines);
+	timelines
+}
+
+fn day07_part2(input: &str) -> u64 {
+	let manifold = day07_manifold(input);
+	let start = manifold[0].iter().position(|&cell| cell == 'S').unwrap();
+	day07_timelines(&manifold, 0, start, &mut AHashMap::new())
+}
+
+fn day08_boxes(input: &str) -> Vec<Vec<i64>> {
+	input.lines().map(|line| line.split(',').map(|coord| coord.parse().unwrap()).collect()).collect()
+}
+
+// every pair of boxes, closest first by squared distance
+fn day08_pairs(boxes: &[Vec<i64>]) -> Vec<(usize, usize)> {
+	(0..boxes.len()).tuple_combinations()
+		.sorted_by_key(|&(a, b)| boxes[a].iter().zip(&boxes[b]).map(|(a, b)| (a - b).pow(2)).sum::<i64>())
+		.collect()
+}
+
+// relabels the whole of one circuit whenever two are joined
+fn day08_join(circuits: &mut [usize], a: usize, b: usize) {
+	let (from, to) = (circuits[b], circuits[a]);
+	circuits.iter_mut().filter(|circuit| **circuit == from).for_each(|circuit| *circuit = to);
+}
+
+fn day08_part1(input: &str) -> u64 {
+	let boxes = day08_boxes(input);
+	let mut circuits = (0..boxes.len()).collect_vec();
+	for (a, b) in day08_pairs(&boxes).into_iter().take(1000) {
+		day08_join(&mut circuits, a, b);
+	}
+
+	// fewer than three circuits leave the missing ones at size zero
+	let sizes = circuits.iter().counts().into_values().sorted().rev().chain(repeat(0));
+	sizes.take(3).product::<usize>() as u64
+}
+
+fn day08_part2(input: &str) -> u64 {
+	let boxes = day08_boxes(input);
+	let mut circuits = (0..boxes.len()).collect_vec();
+	for (a, b) in day08_pairs(&boxes) {
+		day08_join(&mut circuits, a, b);
+		if circuits.iter().all_equal() {
+			return (boxes[a][0] * boxes[b][0]) as u64;
+		}
+	}
+	unreachable!("every box ends up in one circuit")
+}
+
+fn day09_tiles(input: &str) -> Vec<(i64, i64)> {
+	input.lines().map(|line| {
+		let (x, y) = line.split_once(',').unwrap();
+		(x.parse().unwrap(), y.parse().unwrap())
+	}).collect()
+}
+
+fn day09_area((ax, ay): (i64, i64), (bx, by): (i64, i64)) -> u64 {
+	(ax.abs_diff(bx) + 1) * (ay.abs_diff(by) + 1)
+}
+
+fn day09_part1(input: &str) -> u64 {
+	day09_tiles(input).into_iter().tuple_combinations().map(|(a, b)| day09_area(a, b)).max().unwrap()
+}
+
+// on the outline, or inside it by counting the vertical edges to the right
+fn day09_inside(corners: &[(i64, i64)], (x, y): (i64, i64)) -> bool {
+	let edges = corners.iter().copied().circular_tuple_windows::<(_, _)>().collect_vec();
+	let on_edge = edges.iter().any(|&((ax, ay), (bx, by))| {
+		(ax.min(bx)..=ax.max(bx)).contains(&x) && (ay.min(by)..=ay.max(by)).contains(&y)
+	});
+	let crossings = edges.iter()
+		.filter(|&&((ax, ay), (bx, by))| ax == bx && ax > x && (ay.min(by)..ay.max(by)).contains(&y))
+		.count();
+	on_edge || crossings % 2 == 1
+}
+
+// with coordinates doubled, every red tile line and every point halfway between two of them is whole,
+// and checking those is enough to cover every stretch of tiles in between
+fn day09_part2(input: &str) -> u64 {
+	let tiles = day09_tiles(input);
+	let corners = tiles.iter().map(|&(x, y)| (x * 2, y * 2)).collect_vec();
+	let samples = |coords: Vec<i64>| {
+		let coords = coords.into_iter().sorted().dedup().collect_vec();
+		let halfway = coords.iter().tuple_windows().map(|(a, b)| (a + b) / 2).collect_vec();
+		coords.into_iter().chain(halfway).sorted().collect_vec()
+	};
+	let xs = samples(corners.iter().map(|&(x, _)| x).collect());
+	let ys = samples(corners.iter().map(|&(_, y)| y).collect());
+
+	corners.iter().tuple_combinations()
+		.filter(|&(&(ax, ay), &(bx, by))| {
+			let xs = xs.iter().filter(|&&x| (ax.min(bx)..=ax.max(bx)).contains(&x));
+			xs.cartesian_product(ys.iter().filter(|&&y| (ay.min(by)..=ay.max(by)).contains(&y)))
+				.all(|(&x, &y)| day09_inside(&corners, (x, y)))
+		})
+		.map(|(&(ax, ay), &(bx, by))| day09_area((ax / 2, ay / 2), (bx / 2, by / 2)))
+		.max()
+		.unwrap()
+}
+
+type Machine = (Vec<bool>, Vec<Vec<usize>>, Vec<u64>);
+
+fn day10_machines(input: &str) -> Vec<Machine> {
+	input.lines().map(|line| {
+		let tokens = line.split_whitespace().collect_vec();
+		let (lights, rest) = tokens.split_first().unwrap();
+		let (joltage, buttons) = rest.split_last().unwrap();
+		let numbers = |token: &str| token[1..(token.len() - 1)].split(',').map(|number| number.parse().unwrap()).collect_vec();
+
+		let lights = lights.chars().filter_map(|light| match light {
+			'#' => Some(true),
+			'.' => Some(false),
+			_ => None,
+		}).collect();
+		let buttons = buttons.iter().map(|button| numbers(button).into_iter().map(|wire| wire as usize).collect()).collect();
+		(lights, buttons, numbers(joltage))
+	}).collect()
+}
+
+// every combination of buttons, each pressed at most once
+fn day10_part1(input: &str) -> u64 {
+	day10_machines(input).iter().map(|(target, buttons, _)| {
+		(0..(1u32 << buttons.len()))
+			.filter(|pressed| {
+				let mut lights = vec![false; target.len()];
+				buttons.iter().enumerate()
+					.filter(|(ix, _)| pressed & (1 << ix) != 0)
+					.for_each(|(_, button)| button.iter().for_each(|&wire| lights[wire] = !lights[wire]));
+				lights == *target
+			})
+			.map(|pressed| u64::from(pressed.count_ones()))
+			.min()
+			.unwrap()
+	}).sum()
+}
+
+// every number of presses of each button that doesn't overshoot any counter
+fn fewest_presses(buttons: &[Vec<usize>], remaining: &mut [u64]) -> Option<u64> {
+	let Some((button, rest)) = buttons.split_first() else {
+		return remaining.iter().all(|&joltage| joltage == 0).then_some(0);
+	};
+
+	let most = button.iter().map(|&wire| remaining[wire]).min().unwrap();
+	let mut fewest = None;
+	for presses in 0..=most {
+		button.iter().for_each(|&wire| remaining[wire] -= presses);
+		if let Some(rest) = fewest_presses(rest, remaining) {
+			fewest = Some(fewest.unwrap_or(u64::MAX).min(presses + rest));
+		}
+		button.iter().for_each(|&wire| remaining[wire] += presses);
+	}
+	fewest
+}
+
+fn day10_part2(input: &str) -> u64 {
+	day10_machines(input).into_iter()
+		.map(|(_, buttons, mut joltage)| fewest_presses(&buttons, &mut joltage).unwrap())
+		.sum()
+}
+
+fn day11_outputs(input: &str) -> AHashMap<&str, Vec<&str>> {
+	input.lines().map(|line| {
+		let (device, outputs) = line.split_once(": ").unwrap();
+		(device, outputs.split_whitespace().collect())
+	}).collect()
+}
+
+// walks every path to out one at a time, counting those that pass through all of the given devices
+fn day11_paths(input: &str, from: &str, through: &[&str]) -> u64 {
+	let outputs = day11_outputs(input);
+	let mut paths = 0;
+	let mut walks = vec![(from, vec![from])];
+	while let Some((device, path)) = walks.pop() {
+		if device == "out" {
+			if through.iter().all(|device| path.contains(device)) {
+				paths += 1;
+			}
+			continue;
+		}
+		for &output in outputs.get(device).into_iter().flatten() {
+			let mut path = path.clone();
+			path.push(output);
+			walks.push((output, path));
+		}
+	}
+	paths
+}
+
+fn day11_part1(input: &str) -> u64 {
+	day11_paths(input, "you", &[])
+}
+
+fn day11_part2(input: &str) -> u64 {
+	day11_paths(input, "svr", &["dac", "fft"])
+}
+
+#[test]
+fn test_every_day_has_reference() {
+	// only days that can make up inputs can be checked against a reference
+	for solution in get_year(YEAR).unwrap().days.iter().filter(|solution| solution.generator().is_some()) {
+		assert!(
+			REFERENCES.iter().any(|&(day, ..)| day == solution.day()),
+			"no reference solution for day {:0>2}", solution.day()
+		);
+	}
+}
+
+// the references are only worth checking against if they get the examples right themselves
+#[test]
+fn test_references_solve_examples() {
+	let examples = discover_examples(&examples_dir()).unwrap();
+	for &(day, _, part1, part2) in REFERENCES {
+		for example in examples.iter().filter(|example| (example.year, example.day) == (YEAR, day)) {
+			let input = read_input(YEAR, day, &InputSource::File(example.input.clone())).unwrap();
+			for (part, reference) in [(1, part1), (2, part2)] {
+				// day 8's example connects fewer wires than the real puzzle, so its part 1 isn't recorded
+				if let Some(expected) = example.answers.get(day, part) {
+					assert_eq!(*expected, Answer::from(reference(&input)), "day {:0>2} {} part {}", day, example.name, part);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn test_against_references() {
+	let mut failures = vec![];
+	for &(day, scale, part1, part2) in REFERENCES {
+		let solution = get_day(YEAR, day).unwrap();
+		for seed in 0..SEEDS {
+			let input = generate_input(YEAR, day, seed, scale).unwrap();
+			for (part, reference) in [(1, part1), (2, part2)] {
+				let expected = Answer::from(reference(&input));
+				let answer = match part {
+					1 => solution.part1(&input),
+					_ => solution.part2(&input),
+				};
+				match answer {
+					Ok(answer) if answer == expected => {},
+					Ok(answer) => failures.push(format!("day {:0>2} part {} seed {}: expected {}, got {}", day, part, seed, expected, answer)),
+					Err(err) => failures.push(format!("day {:0>2} part {} seed {}: {}", day, part, seed, err)),
+				}
+			}
+		}
+	}
+
+	// reproduce a failure with `aoc generate <day> --seed <seed> --scale <scale>`
+	assert!(failures.is_empty(), "{} answers differ from the reference solutions:\n{}", failures.len(), failures.join("\n"));
+}
\ No newline at end of file
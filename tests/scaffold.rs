@@ -0,0 +1,61 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use aoc25::scaffold_day;
+
+fn copy_dir(from: &Path, to: &Path) {
+	fs::create_dir_all(to).unwrap();
+	for entry in fs::read_dir(from).unwrap() {
+		let path = entry.unwrap().path();
+		let target = to.join(path.file_name().unwrap());
+		if path.is_dir() {
+			copy_dir(&path, &target);
+		} else {
+			fs::copy(&path, &target).unwrap();
+		}
+	}
+}
+
+// removes the copy, and everything built from it, however the test ends
+struct Scratch(PathBuf);
+impl Drop for Scratch {
+	fn drop(&mut self) {
+		let _ = fs::remove_dir_all(&self.0);
+	}
+}
+
+// a freshly scaffolded day has to leave the whole suite green, so scaffold a copy of the crate and test that
+// building the copy from scratch takes a while, so this only runs when asked for with `cargo test -- --ignored`
+#[test]
+#[ignore = "builds and tests a whole copy of the crate"]
+fn test_scaffolded_crate_passes() {
+	let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let scratch = Scratch(env::temp_dir().join(format!("aoc25-scaffolded-{}", std::process::id())));
+	let root = scratch.0.join("crate");
+	let _ = fs::remove_dir_all(&scratch.0);
+	for dir in ["src", "tests", "benches"] {
+		copy_dir(&crate_dir.join(dir), &root.join(dir));
+	}
+	for file in ["Cargo.toml", "Cargo.lock"].map(|file| crate_dir.join(file)).iter().filter(|file| file.is_file()) {
+		fs::copy(file, root.join(file.file_name().unwrap())).unwrap();
+	}
+	// the copy mustn't scaffold and test a copy of itself in turn
+	fs::remove_file(root.join("tests").join("scaffold.rs")).unwrap();
+
+	// a day added to a year that already has some, leaving a gap, and the first day of a new year
+	scaffold_day(&root, 2025, 14, "Scaffolded").unwrap();
+	scaffold_day(&root, 2026, 3, "Next Year").unwrap();
+
+	let output = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()))
+		.args(["test", "--offline", "--quiet"])
+		.current_dir(&root)
+		.env("CARGO_TARGET_DIR", scratch.0.join("target"))
+		.output()
+		.unwrap();
+	assert!(
+		output.status.success(),
+		"tests failed once days were scaffolded:\n{}\n{}",
+		String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr)
+	);
+}
\ No newline at end of file